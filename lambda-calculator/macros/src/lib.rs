@@ -0,0 +1,96 @@
+// -- Imports -- //
+
+use lambda_calculator::{Parser, Term};
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// -- Macros -- //
+
+// DOCS Builds a `Term` from an expression that is checked when the crate is compiled, so that
+// 		syntax errors are compile errors with the same messages as `LambdaError`. The expression
+// 		can be written directly, like `lambda!(λx y.x y)`, or as a string literal, which is
+// 		needed for `\x.` since Rust can't read a backslash outside of a literal.
+// 		No keywords are defined, so numbers are the only shorthand.
+#[proc_macro]
+pub fn lambda(input: TokenStream) -> TokenStream {
+	let (source, span) = read_source(input);
+	
+	match Term::parse(&source, &Parser::new()) {
+		Ok(term) => construct(&term).parse().unwrap_or_default(),
+		Err(e) => compile_error(&e.underline(&source), span),
+	}
+}
+
+// -- Reading -- //
+
+// DOCS Tokens are read back as text, which keeps `λx` together as one identifier, but the
+// 		lexer splits the `λ` off again anyway.
+fn read_source(input: TokenStream) -> (String, Span) {
+	let mut tokens = input.clone().into_iter();
+	
+	if let (Some(TokenTree::Literal(literal)), None) = (tokens.next(), tokens.next())
+		&& let Some(text) = unquote(&literal.to_string()) {
+		return (text, literal.span())
+	}
+	
+	(input.to_string(), Span::call_site())
+}
+
+fn unquote(literal: &str) -> Option<String> {
+	if let Some(raw) = literal.strip_prefix('r') {
+		let hashes = raw.len() - raw.trim_start_matches('#').len();
+		return raw.get(hashes + 1..raw.len() - hashes - 1).map(String::from)
+	}
+	
+	let inner = literal.strip_prefix('"')?.strip_suffix('"')?;
+	let mut out = String::with_capacity(inner.len());
+	let mut chars = inner.chars();
+	
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			out.push(c);
+			continue
+		}
+		
+		match chars.next()? {
+			'n' => out.push('\n'),
+			't' => out.push('\t'),
+			'r' => out.push('\r'),
+			'0' => out.push('\0'),
+			'\n' => while chars.clone().next().is_some_and(char::is_whitespace) { chars.next(); },
+			c => out.push(c),
+		}
+	}
+	
+	Some(out)
+}
+
+// -- Writing -- //
+
+fn construct(term: &Term) -> String {
+	match term {
+		Term::Var(name) => format!("::lambda_calculator::Term::var({name:?})"),
+		Term::Lam(name, body) => format!("::lambda_calculator::Term::lam({name:?}, {})", construct(body)),
+		Term::App(function, argument) =>
+			format!("::lambda_calculator::Term::app({}, {})", construct(function), construct(argument)),
+		Term::Int(value) => format!("::lambda_calculator::Term::Int({value}i64)"),
+		Term::Prim(op) => format!("::lambda_calculator::Term::Prim(::lambda_calculator::Primitive::{op:?})"),
+	}
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+	let mut message = Literal::string(message);
+	message.set_span(span);
+	
+	let mut arguments = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+	arguments.set_span(span);
+	
+	let mut bang = Punct::new('!', Spacing::Alone);
+	bang.set_span(span);
+	
+	TokenStream::from_iter([
+		TokenTree::Ident(Ident::new("compile_error", span)),
+		TokenTree::Punct(bang),
+		TokenTree::Group(arguments),
+	])
+}
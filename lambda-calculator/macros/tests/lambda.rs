@@ -0,0 +1,35 @@
+// -- Imports -- //
+
+use lambda_calculator::Term;
+use lambda_calculator_macros::lambda;
+
+// -- Tests -- //
+
+fn identity() -> Term { Term::lam("x", Term::var("x")) }
+
+#[test]
+fn tokens_expand_to_terms() {
+	assert_eq!(lambda!(λx.x), identity());
+	assert_eq!(lambda!(λx y.x y), Term::lam("x", Term::lam("y", Term::app(Term::var("x"), Term::var("y")))));
+	assert_eq!(lambda!((λx.x) a), Term::app(identity(), Term::var("a")));
+	assert_eq!(lambda!(2), Term::lam("f", Term::lam("x", Term::app(Term::var("f"), Term::app(Term::var("f"), Term::var("x"))))));
+}
+
+#[test]
+fn literals_expand_to_terms() {
+	assert_eq!(lambda!("\\x.x"), identity());
+	assert_eq!(lambda!(r"\x.x"), identity());
+	assert_eq!(lambda!("λx.\
+		x"), identity());
+}
+
+#[test]
+fn expanded_terms_normalize() {
+	assert_eq!(lambda!((λx.x) a).normalize().unwrap(), Term::var("a"));
+	assert_eq!(lambda!("(\\f x.f (f x)) g y").normalize().unwrap().to_string(), "g (g y)");
+}
+
+#[test]
+fn syntax_errors_fail_to_compile() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
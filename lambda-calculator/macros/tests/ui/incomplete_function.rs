@@ -0,0 +1,5 @@
+use lambda_calculator_macros::lambda;
+
+fn main() {
+	let _ = lambda!("\\x y");
+}
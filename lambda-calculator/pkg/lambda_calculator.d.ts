@@ -1,10 +1,188 @@
 /* tslint:disable */
 /* eslint-disable */
-export function simplify(expression: string, parser: Parser): string;
+
+export enum Arithmetic {
+    Church = 0,
+    Native = 1,
+}
+
+export class Budget {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    static new(): Budget;
+    static unlimited(): Budget;
+    get max_size(): number | undefined;
+    set max_size(value: number | null | undefined);
+    get max_steps(): number | undefined;
+    set max_steps(value: number | null | undefined);
+    get max_time(): number | undefined;
+    set max_time(value: number | null | undefined);
+}
+
+export enum Equivalence {
+    AlphaEquivalent = 0,
+    BetaEquivalent = 1,
+    Different = 2,
+    Undecided = 3,
+}
+
+export enum ErrorKind {
+    Syntax = 0,
+    Naming = 1,
+    Input = 2,
+    Arithmetic = 3,
+    Budget = 4,
+    Assertion = 5,
+    Divergence = 6,
+    Internal = 7,
+}
+
+export class ErrorReport {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    kind: ErrorKind;
+    message: string;
+    get span(): Span | undefined;
+    set span(value: Span | null | undefined);
+}
+
+export enum Eta {
+    Off = 0,
+    Reduce = 1,
+    Expand = 2,
+}
+
+export class EvalResult {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    get error_kind(): ErrorKind | undefined;
+    set error_kind(value: ErrorKind | null | undefined);
+    output: string;
+    size: number;
+    get span(): Span | undefined;
+    set span(value: Span | null | undefined);
+    status: Status;
+    steps: number;
+}
+
+export class LineResult {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    line: number;
+    get name(): string | undefined;
+    set name(value: string | null | undefined);
+    get passed(): boolean | undefined;
+    set passed(value: boolean | null | undefined);
+    result: EvalResult;
+}
+
 export class Parser {
-  private constructor();
-  free(): void;
-  static new(): Parser;
-  create_keyword(name: string, expression: string): string;
-  remove_keyword(name: string): void;
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    arithmetic(): Arithmetic;
+    budget(): Budget;
+    create_keyword(name: string, expression: string): string;
+    define_keyword(name: string, expression: string): string;
+    define_keyword_group(names: string[], expressions: string[]): void;
+    dependents_of(name: string): string[];
+    eta(): Eta;
+    keyword_expression(name: string): string | undefined;
+    keyword_names(): string[];
+    load_program(text: string): LineResult[];
+    load_program_with(text: string, strategy: Strategy): LineResult[];
+    static new(): Parser;
+    remove_keyword(name: string): void;
+    run_tests(text: string): TestReport;
+    run_tests_with(text: string, strategy: Strategy): TestReport;
+    set_arithmetic(arithmetic: Arithmetic): void;
+    set_budget(budget: Budget): void;
+    set_eta(eta: Eta): void;
+    set_style(style: Style): void;
+    style(): Style;
+    static with_prelude(): Parser;
 }
+
+export class Span {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    readonly end: number;
+    readonly start: number;
+}
+
+export enum Status {
+    NormalForm = 0,
+    Error = 1,
+    BudgetExhausted = 2,
+    Diverged = 3,
+}
+
+export enum Strategy {
+    Normal = 0,
+    Applicative = 1,
+    CallByName = 2,
+    CallByValue = 3,
+}
+
+export class Style {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    static new(): Style;
+    ascii: boolean;
+    compress_binders: boolean;
+    decode: boolean;
+    fold_keywords: boolean;
+    minimal_parens: boolean;
+    get width(): number | undefined;
+    set width(value: number | null | undefined);
+}
+
+export class TestFailure {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    line: number;
+    message: string;
+    get span(): Span | undefined;
+    set span(value: Span | null | undefined);
+}
+
+export class TestReport {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    summary(): string;
+    failed: number;
+    failures: TestFailure[];
+    passed: number;
+}
+
+export class TraceStep {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    get failure(): Status | undefined;
+    set failure(value: Status | null | undefined);
+    redex: string;
+    term: string;
+}
+
+export function equivalent(a: string, b: string, parser: Parser): Equivalence;
+
+export function evaluate(expression: string, parser: Parser, strategy: Strategy): EvalResult;
+
+export function evaluate_within(expression: string, parser: Parser, strategy: Strategy, budget: Budget): EvalResult;
+
+export function simplify(expression: string, parser: Parser): string;
+
+export function simplify_with(expression: string, parser: Parser, strategy: Strategy): string;
+
+export function simplify_within(expression: string, parser: Parser, strategy: Strategy, budget: Budget): string;
+
+export function trace(expression: string, parser: Parser, strategy: Strategy): TraceStep[];
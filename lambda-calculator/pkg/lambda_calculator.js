@@ -1,5 +1,9 @@
+/* @ts-self-types="./lambda_calculator.d.ts" */
 import * as wasm from "./lambda_calculator_bg.wasm";
-export * from "./lambda_calculator_bg.js";
 import { __wbg_set_wasm } from "./lambda_calculator_bg.js";
+
 __wbg_set_wasm(wasm);
 wasm.__wbindgen_start();
+export {
+    Arithmetic, Budget, Equivalence, ErrorKind, ErrorReport, Eta, EvalResult, LineResult, Parser, Span, Status, Strategy, Style, TestFailure, TestReport, TraceStep, equivalent, evaluate, evaluate_within, simplify, simplify_with, simplify_within, trace
+} from "./lambda_calculator_bg.js";
@@ -1,17 +1,1290 @@
-let wasm;
-export function __wbg_set_wasm(val) {
-    wasm = val;
+/**
+ * @enum {0 | 1}
+ */
+export const Arithmetic = Object.freeze({
+    Church: 0, "0": "Church",
+    Native: 1, "1": "Native",
+});
+
+export class Budget {
+    static __wrap(ptr) {
+        const obj = Object.create(Budget.prototype);
+        obj.__wbg_ptr = ptr;
+        BudgetFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        BudgetFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_budget_free(ptr, 0);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get max_size() {
+        const ret = wasm.budget_max_size(this.__wbg_ptr);
+        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get max_steps() {
+        const ret = wasm.budget_max_steps(this.__wbg_ptr);
+        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get max_time() {
+        const ret = wasm.budget_max_time(this.__wbg_ptr);
+        return ret[0] === 0 ? undefined : ret[1];
+    }
+    /**
+     * @returns {Budget}
+     */
+    static new() {
+        const ret = wasm.budget_new();
+        return Budget.__wrap(ret);
+    }
+    /**
+     * @param {number | null} [max_size]
+     */
+    set max_size(max_size) {
+        wasm.budget_set_max_size(this.__wbg_ptr, isLikeNone(max_size) ? Number.MAX_SAFE_INTEGER : (max_size) >>> 0);
+    }
+    /**
+     * @param {number | null} [max_steps]
+     */
+    set max_steps(max_steps) {
+        wasm.budget_set_max_steps(this.__wbg_ptr, isLikeNone(max_steps) ? Number.MAX_SAFE_INTEGER : (max_steps) >>> 0);
+    }
+    /**
+     * @param {number | null} [max_time]
+     */
+    set max_time(max_time) {
+        wasm.budget_set_max_time(this.__wbg_ptr, !isLikeNone(max_time), isLikeNone(max_time) ? 0 : max_time);
+    }
+    /**
+     * @returns {Budget}
+     */
+    static unlimited() {
+        const ret = wasm.budget_unlimited();
+        return Budget.__wrap(ret);
+    }
+}
+if (Symbol.dispose) Budget.prototype[Symbol.dispose] = Budget.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2 | 3}
+ */
+export const Equivalence = Object.freeze({
+    AlphaEquivalent: 0, "0": "AlphaEquivalent",
+    BetaEquivalent: 1, "1": "BetaEquivalent",
+    Different: 2, "2": "Different",
+    Undecided: 3, "3": "Undecided",
+});
+
+/**
+ * @enum {0 | 1 | 2 | 3 | 4 | 5 | 6 | 7}
+ */
+export const ErrorKind = Object.freeze({
+    Syntax: 0, "0": "Syntax",
+    Naming: 1, "1": "Naming",
+    Input: 2, "2": "Input",
+    Arithmetic: 3, "3": "Arithmetic",
+    Budget: 4, "4": "Budget",
+    Assertion: 5, "5": "Assertion",
+    Divergence: 6, "6": "Divergence",
+    Internal: 7, "7": "Internal",
+});
+
+export class ErrorReport {
+    static __wrap(ptr) {
+        const obj = Object.create(ErrorReport.prototype);
+        obj.__wbg_ptr = ptr;
+        ErrorReportFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ErrorReportFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_errorreport_free(ptr, 0);
+    }
+    /**
+     * @returns {ErrorKind}
+     */
+    get kind() {
+        const ret = wasm.__wbg_get_errorreport_kind(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.__wbg_get_errorreport_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {Span | undefined}
+     */
+    get span() {
+        const ret = wasm.__wbg_get_errorreport_span(this.__wbg_ptr);
+        return ret === 0 ? undefined : Span.__wrap(ret);
+    }
+    /**
+     * @param {ErrorKind} arg0
+     */
+    set kind(arg0) {
+        wasm.__wbg_set_errorreport_kind(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set message(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_errorreport_message(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {Span | null} [arg0]
+     */
+    set span(arg0) {
+        let ptr0 = 0;
+        if (!isLikeNone(arg0)) {
+            _assertClass(arg0, Span);
+            ptr0 = arg0.__destroy_into_raw();
+        }
+        wasm.__wbg_set_errorreport_span(this.__wbg_ptr, ptr0);
+    }
+}
+if (Symbol.dispose) ErrorReport.prototype[Symbol.dispose] = ErrorReport.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2}
+ */
+export const Eta = Object.freeze({
+    Off: 0, "0": "Off",
+    Reduce: 1, "1": "Reduce",
+    Expand: 2, "2": "Expand",
+});
+
+export class EvalResult {
+    static __wrap(ptr) {
+        const obj = Object.create(EvalResult.prototype);
+        obj.__wbg_ptr = ptr;
+        EvalResultFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        EvalResultFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_evalresult_free(ptr, 0);
+    }
+    /**
+     * @returns {ErrorKind | undefined}
+     */
+    get error_kind() {
+        const ret = wasm.__wbg_get_evalresult_error_kind(this.__wbg_ptr);
+        return ret === 8 ? undefined : ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get output() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.__wbg_get_evalresult_output(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {number}
+     */
+    get size() {
+        const ret = wasm.__wbg_get_evalresult_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Span | undefined}
+     */
+    get span() {
+        const ret = wasm.__wbg_get_evalresult_span(this.__wbg_ptr);
+        return ret === 0 ? undefined : Span.__wrap(ret);
+    }
+    /**
+     * @returns {Status}
+     */
+    get status() {
+        const ret = wasm.__wbg_get_evalresult_status(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get steps() {
+        const ret = wasm.__wbg_get_evalresult_steps(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {ErrorKind | null} [arg0]
+     */
+    set error_kind(arg0) {
+        wasm.__wbg_set_evalresult_error_kind(this.__wbg_ptr, isLikeNone(arg0) ? 8 : arg0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set output(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_evalresult_output(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set size(arg0) {
+        wasm.__wbg_set_evalresult_size(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {Span | null} [arg0]
+     */
+    set span(arg0) {
+        let ptr0 = 0;
+        if (!isLikeNone(arg0)) {
+            _assertClass(arg0, Span);
+            ptr0 = arg0.__destroy_into_raw();
+        }
+        wasm.__wbg_set_evalresult_span(this.__wbg_ptr, ptr0);
+    }
+    /**
+     * @param {Status} arg0
+     */
+    set status(arg0) {
+        wasm.__wbg_set_evalresult_status(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set steps(arg0) {
+        wasm.__wbg_set_evalresult_steps(this.__wbg_ptr, arg0);
+    }
+}
+if (Symbol.dispose) EvalResult.prototype[Symbol.dispose] = EvalResult.prototype.free;
+
+export class LineResult {
+    static __wrap(ptr) {
+        const obj = Object.create(LineResult.prototype);
+        obj.__wbg_ptr = ptr;
+        LineResultFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LineResultFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_lineresult_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get line() {
+        const ret = wasm.__wbg_get_lineresult_line(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {string | undefined}
+     */
+    get name() {
+        const ret = wasm.__wbg_get_lineresult_name(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getStringFromWasm0(ret[0], ret[1]);
+            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        }
+        return v1;
+    }
+    /**
+     * @returns {boolean | undefined}
+     */
+    get passed() {
+        const ret = wasm.__wbg_get_lineresult_passed(this.__wbg_ptr);
+        return ret === 0xFFFFFF ? undefined : ret !== 0;
+    }
+    /**
+     * @returns {EvalResult}
+     */
+    get result() {
+        const ret = wasm.__wbg_get_lineresult_result(this.__wbg_ptr);
+        return EvalResult.__wrap(ret);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set line(arg0) {
+        wasm.__wbg_set_lineresult_line(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {string | null} [arg0]
+     */
+    set name(arg0) {
+        var ptr0 = isLikeNone(arg0) ? 0 : passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_lineresult_name(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {boolean | null} [arg0]
+     */
+    set passed(arg0) {
+        wasm.__wbg_set_lineresult_passed(this.__wbg_ptr, isLikeNone(arg0) ? 0xFFFFFF : arg0 ? 1 : 0);
+    }
+    /**
+     * @param {EvalResult} arg0
+     */
+    set result(arg0) {
+        _assertClass(arg0, EvalResult);
+        var ptr0 = arg0.__destroy_into_raw();
+        wasm.__wbg_set_lineresult_result(this.__wbg_ptr, ptr0);
+    }
+}
+if (Symbol.dispose) LineResult.prototype[Symbol.dispose] = LineResult.prototype.free;
+
+export class Parser {
+    static __wrap(ptr) {
+        const obj = Object.create(Parser.prototype);
+        obj.__wbg_ptr = ptr;
+        ParserFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ParserFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_parser_free(ptr, 0);
+    }
+    /**
+     * @returns {Arithmetic}
+     */
+    arithmetic() {
+        const ret = wasm.parser_arithmetic(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {Budget}
+     */
+    budget() {
+        const ret = wasm.parser_budget(this.__wbg_ptr);
+        return Budget.__wrap(ret);
+    }
+    /**
+     * @param {string} name
+     * @param {string} expression
+     * @returns {string}
+     */
+    create_keyword(name, expression) {
+        let deferred3_0;
+        let deferred3_1;
+        try {
+            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len1 = WASM_VECTOR_LEN;
+            const ret = wasm.parser_create_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);
+            deferred3_0 = ret[0];
+            deferred3_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        }
+    }
+    /**
+     * @param {string} name
+     * @param {string} expression
+     * @returns {string}
+     */
+    define_keyword(name, expression) {
+        let deferred4_0;
+        let deferred4_1;
+        try {
+            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len1 = WASM_VECTOR_LEN;
+            const ret = wasm.parser_define_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);
+            var ptr3 = ret[0];
+            var len3 = ret[1];
+            if (ret[3]) {
+                ptr3 = 0; len3 = 0;
+                throw takeFromExternrefTable0(ret[2]);
+            }
+            deferred4_0 = ptr3;
+            deferred4_1 = len3;
+            return getStringFromWasm0(ptr3, len3);
+        } finally {
+            wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);
+        }
+    }
+    /**
+     * @param {string[]} names
+     * @param {string[]} expressions
+     */
+    define_keyword_group(names, expressions) {
+        const ptr0 = passArrayJsValueToWasm0(names, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArrayJsValueToWasm0(expressions, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_define_keyword_group(this.__wbg_ptr, ptr0, len0, ptr1, len1);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {string} name
+     * @returns {string[]}
+     */
+    dependents_of(name) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_dependents_of(this.__wbg_ptr, ptr0, len0);
+        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v2;
+    }
+    /**
+     * @returns {Eta}
+     */
+    eta() {
+        const ret = wasm.parser_eta(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {string} name
+     * @returns {string | undefined}
+     */
+    keyword_expression(name) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_keyword_expression(this.__wbg_ptr, ptr0, len0);
+        let v2;
+        if (ret[0] !== 0) {
+            v2 = getStringFromWasm0(ret[0], ret[1]);
+            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        }
+        return v2;
+    }
+    /**
+     * @returns {string[]}
+     */
+    keyword_names() {
+        const ret = wasm.parser_keyword_names(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @param {string} text
+     * @returns {LineResult[]}
+     */
+    load_program(text) {
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_load_program(this.__wbg_ptr, ptr0, len0);
+        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v2;
+    }
+    /**
+     * @param {string} text
+     * @param {Strategy} strategy
+     * @returns {LineResult[]}
+     */
+    load_program_with(text, strategy) {
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_load_program_with(this.__wbg_ptr, ptr0, len0, strategy);
+        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v2;
+    }
+    /**
+     * @returns {Parser}
+     */
+    static new() {
+        const ret = wasm.parser_new();
+        return Parser.__wrap(ret);
+    }
+    /**
+     * @param {string} name
+     */
+    remove_keyword(name) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.parser_remove_keyword(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string} text
+     * @returns {TestReport}
+     */
+    run_tests(text) {
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_run_tests(this.__wbg_ptr, ptr0, len0);
+        return TestReport.__wrap(ret);
+    }
+    /**
+     * @param {string} text
+     * @param {Strategy} strategy
+     * @returns {TestReport}
+     */
+    run_tests_with(text, strategy) {
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.parser_run_tests_with(this.__wbg_ptr, ptr0, len0, strategy);
+        return TestReport.__wrap(ret);
+    }
+    /**
+     * @param {Arithmetic} arithmetic
+     */
+    set_arithmetic(arithmetic) {
+        wasm.parser_set_arithmetic(this.__wbg_ptr, arithmetic);
+    }
+    /**
+     * @param {Budget} budget
+     */
+    set_budget(budget) {
+        _assertClass(budget, Budget);
+        wasm.parser_set_budget(this.__wbg_ptr, budget.__wbg_ptr);
+    }
+    /**
+     * @param {Eta} eta
+     */
+    set_eta(eta) {
+        wasm.parser_set_eta(this.__wbg_ptr, eta);
+    }
+    /**
+     * @param {Style} style
+     */
+    set_style(style) {
+        _assertClass(style, Style);
+        wasm.parser_set_style(this.__wbg_ptr, style.__wbg_ptr);
+    }
+    /**
+     * @returns {Style}
+     */
+    style() {
+        const ret = wasm.parser_style(this.__wbg_ptr);
+        return Style.__wrap(ret);
+    }
+    /**
+     * @returns {Parser}
+     */
+    static with_prelude() {
+        const ret = wasm.parser_with_prelude();
+        return Parser.__wrap(ret);
+    }
+}
+if (Symbol.dispose) Parser.prototype[Symbol.dispose] = Parser.prototype.free;
+
+export class Span {
+    static __wrap(ptr) {
+        const obj = Object.create(Span.prototype);
+        obj.__wbg_ptr = ptr;
+        SpanFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SpanFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_span_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get end() {
+        const ret = wasm.span_end(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get start() {
+        const ret = wasm.span_start(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+}
+if (Symbol.dispose) Span.prototype[Symbol.dispose] = Span.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2 | 3}
+ */
+export const Status = Object.freeze({
+    NormalForm: 0, "0": "NormalForm",
+    Error: 1, "1": "Error",
+    BudgetExhausted: 2, "2": "BudgetExhausted",
+    Diverged: 3, "3": "Diverged",
+});
+
+/**
+ * @enum {0 | 1 | 2 | 3}
+ */
+export const Strategy = Object.freeze({
+    Normal: 0, "0": "Normal",
+    Applicative: 1, "1": "Applicative",
+    CallByName: 2, "2": "CallByName",
+    CallByValue: 3, "3": "CallByValue",
+});
+
+export class Style {
+    static __wrap(ptr) {
+        const obj = Object.create(Style.prototype);
+        obj.__wbg_ptr = ptr;
+        StyleFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        StyleFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_style_free(ptr, 0);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get ascii() {
+        const ret = wasm.style_ascii(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get compress_binders() {
+        const ret = wasm.style_compress_binders(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get decode() {
+        const ret = wasm.style_decode(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get fold_keywords() {
+        const ret = wasm.style_fold_keywords(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get minimal_parens() {
+        const ret = wasm.style_minimal_parens(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {Style}
+     */
+    static new() {
+        const ret = wasm.style_new();
+        return Style.__wrap(ret);
+    }
+    /**
+     * @param {boolean} ascii
+     */
+    set ascii(ascii) {
+        wasm.style_set_ascii(this.__wbg_ptr, ascii);
+    }
+    /**
+     * @param {boolean} compress_binders
+     */
+    set compress_binders(compress_binders) {
+        wasm.style_set_compress_binders(this.__wbg_ptr, compress_binders);
+    }
+    /**
+     * @param {boolean} decode
+     */
+    set decode(decode) {
+        wasm.style_set_decode(this.__wbg_ptr, decode);
+    }
+    /**
+     * @param {boolean} fold_keywords
+     */
+    set fold_keywords(fold_keywords) {
+        wasm.style_set_fold_keywords(this.__wbg_ptr, fold_keywords);
+    }
+    /**
+     * @param {boolean} minimal_parens
+     */
+    set minimal_parens(minimal_parens) {
+        wasm.style_set_minimal_parens(this.__wbg_ptr, minimal_parens);
+    }
+    /**
+     * @param {number | null} [width]
+     */
+    set width(width) {
+        wasm.style_set_width(this.__wbg_ptr, isLikeNone(width) ? Number.MAX_SAFE_INTEGER : (width) >>> 0);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get width() {
+        const ret = wasm.style_width(this.__wbg_ptr);
+        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;
+    }
+}
+if (Symbol.dispose) Style.prototype[Symbol.dispose] = Style.prototype.free;
+
+export class TestFailure {
+    static __wrap(ptr) {
+        const obj = Object.create(TestFailure.prototype);
+        obj.__wbg_ptr = ptr;
+        TestFailureFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    static __unwrap(jsValue) {
+        if (!(jsValue instanceof TestFailure)) {
+            return 0;
+        }
+        return jsValue.__destroy_into_raw();
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        TestFailureFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_testfailure_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get line() {
+        const ret = wasm.__wbg_get_testfailure_line(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.__wbg_get_testfailure_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {Span | undefined}
+     */
+    get span() {
+        const ret = wasm.__wbg_get_testfailure_span(this.__wbg_ptr);
+        return ret === 0 ? undefined : Span.__wrap(ret);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set line(arg0) {
+        wasm.__wbg_set_testfailure_line(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set message(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_testfailure_message(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {Span | null} [arg0]
+     */
+    set span(arg0) {
+        let ptr0 = 0;
+        if (!isLikeNone(arg0)) {
+            _assertClass(arg0, Span);
+            ptr0 = arg0.__destroy_into_raw();
+        }
+        wasm.__wbg_set_testfailure_span(this.__wbg_ptr, ptr0);
+    }
+}
+if (Symbol.dispose) TestFailure.prototype[Symbol.dispose] = TestFailure.prototype.free;
+
+export class TestReport {
+    static __wrap(ptr) {
+        const obj = Object.create(TestReport.prototype);
+        obj.__wbg_ptr = ptr;
+        TestReportFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        TestReportFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_testreport_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get failed() {
+        const ret = wasm.__wbg_get_testreport_failed(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {TestFailure[]}
+     */
+    get failures() {
+        const ret = wasm.__wbg_get_testreport_failures(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get passed() {
+        const ret = wasm.__wbg_get_testreport_passed(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {number} arg0
+     */
+    set failed(arg0) {
+        wasm.__wbg_set_testreport_failed(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {TestFailure[]} arg0
+     */
+    set failures(arg0) {
+        const ptr0 = passArrayJsValueToWasm0(arg0, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_testreport_failures(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set passed(arg0) {
+        wasm.__wbg_set_testreport_passed(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @returns {string}
+     */
+    summary() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.testreport_summary(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+if (Symbol.dispose) TestReport.prototype[Symbol.dispose] = TestReport.prototype.free;
+
+export class TraceStep {
+    static __wrap(ptr) {
+        const obj = Object.create(TraceStep.prototype);
+        obj.__wbg_ptr = ptr;
+        TraceStepFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        TraceStepFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_tracestep_free(ptr, 0);
+    }
+    /**
+     * @returns {Status | undefined}
+     */
+    get failure() {
+        const ret = wasm.__wbg_get_tracestep_failure(this.__wbg_ptr);
+        return ret === 4 ? undefined : ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get redex() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.__wbg_get_tracestep_redex(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get term() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.__wbg_get_tracestep_term(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @param {Status | null} [arg0]
+     */
+    set failure(arg0) {
+        wasm.__wbg_set_tracestep_failure(this.__wbg_ptr, isLikeNone(arg0) ? 4 : arg0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set redex(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_tracestep_redex(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set term(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_tracestep_term(this.__wbg_ptr, ptr0, len0);
+    }
+}
+if (Symbol.dispose) TraceStep.prototype[Symbol.dispose] = TraceStep.prototype.free;
+
+/**
+ * @param {string} a
+ * @param {string} b
+ * @param {Parser} parser
+ * @returns {Equivalence}
+ */
+export function equivalent(a, b, parser) {
+    const ptr0 = passStringToWasm0(a, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ptr1 = passStringToWasm0(b, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    _assertClass(parser, Parser);
+    const ret = wasm.equivalent(ptr0, len0, ptr1, len1, parser.__wbg_ptr);
+    if (ret[2]) {
+        throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0];
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @param {Strategy} strategy
+ * @returns {EvalResult}
+ */
+export function evaluate(expression, parser, strategy) {
+    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    _assertClass(parser, Parser);
+    const ret = wasm.evaluate(ptr0, len0, parser.__wbg_ptr, strategy);
+    return EvalResult.__wrap(ret);
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @param {Strategy} strategy
+ * @param {Budget} budget
+ * @returns {EvalResult}
+ */
+export function evaluate_within(expression, parser, strategy, budget) {
+    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    _assertClass(parser, Parser);
+    _assertClass(budget, Budget);
+    const ret = wasm.evaluate_within(ptr0, len0, parser.__wbg_ptr, strategy, budget.__wbg_ptr);
+    return EvalResult.__wrap(ret);
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @returns {string}
+ */
+export function simplify(expression, parser) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(parser, Parser);
+        const ret = wasm.simplify(ptr0, len0, parser.__wbg_ptr);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @param {Strategy} strategy
+ * @returns {string}
+ */
+export function simplify_with(expression, parser, strategy) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(parser, Parser);
+        const ret = wasm.simplify_with(ptr0, len0, parser.__wbg_ptr, strategy);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @param {Strategy} strategy
+ * @param {Budget} budget
+ * @returns {string}
+ */
+export function simplify_within(expression, parser, strategy, budget) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(parser, Parser);
+        _assertClass(budget, Budget);
+        const ret = wasm.simplify_within(ptr0, len0, parser.__wbg_ptr, strategy, budget.__wbg_ptr);
+        deferred2_0 = ret[0];
+        deferred2_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+    }
+}
+
+/**
+ * @param {string} expression
+ * @param {Parser} parser
+ * @param {Strategy} strategy
+ * @returns {TraceStep[]}
+ */
+export function trace(expression, parser, strategy) {
+    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    _assertClass(parser, Parser);
+    const ret = wasm.trace(ptr0, len0, parser.__wbg_ptr, strategy);
+    var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v2;
+}
+export function __wbg___wbindgen_string_get_0380ccaa2f57f0d9(arg0, arg1) {
+    const obj = arg1;
+    const ret = typeof(obj) === 'string' ? obj : undefined;
+    var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    var len1 = WASM_VECTOR_LEN;
+    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+}
+export function __wbg___wbindgen_throw_41e9ee4f547fc59a(arg0, arg1) {
+    throw new Error(getStringFromWasm0(arg0, arg1));
+}
+export function __wbg_error_757e9472f8410341(arg0, arg1) {
+    let deferred0_0;
+    let deferred0_1;
+    try {
+        deferred0_0 = arg0;
+        deferred0_1 = arg1;
+        console.error(getStringFromWasm0(arg0, arg1));
+    } finally {
+        wasm.__wbindgen_free(deferred0_0, deferred0_1, 1);
+    }
+}
+export function __wbg_errorreport_new(arg0) {
+    const ret = ErrorReport.__wrap(arg0);
+    return ret;
+}
+export function __wbg_lineresult_new(arg0) {
+    const ret = LineResult.__wrap(arg0);
+    return ret;
+}
+export function __wbg_new_227d7c05414eb861() {
+    const ret = new Error();
+    return ret;
+}
+export function __wbg_now_aa4ccb83129e9e55() {
+    const ret = Date.now();
+    return ret;
+}
+export function __wbg_stack_3b0d974bbf31e44f(arg0, arg1) {
+    const ret = arg1.stack;
+    const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len1 = WASM_VECTOR_LEN;
+    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+}
+export function __wbg_testfailure_new(arg0) {
+    const ret = TestFailure.__wrap(arg0);
+    return ret;
+}
+export function __wbg_testfailure_unwrap(arg0) {
+    const ret = TestFailure.__unwrap(arg0);
+    return ret;
+}
+export function __wbg_tracestep_new(arg0) {
+    const ret = TraceStep.__wrap(arg0);
+    return ret;
+}
+export function __wbindgen_generic_0000000000000001(arg0, arg1) {
+    // Cast intrinsic for `Ref(String) -> Externref`.
+    const ret = getStringFromWasm0(arg0, arg1);
+    return ret;
+}
+export function __wbindgen_init_externref_table() {
+    const table = wasm.__wbindgen_externrefs;
+    const offset = table.grow(4);
+    table.set(0, undefined);
+    table.set(offset + 0, undefined);
+    table.set(offset + 1, null);
+    table.set(offset + 2, true);
+    table.set(offset + 3, false);
+}
+const BudgetFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_budget_free(ptr, 1));
+const ErrorReportFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_errorreport_free(ptr, 1));
+const EvalResultFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_evalresult_free(ptr, 1));
+const LineResultFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_lineresult_free(ptr, 1));
+const ParserFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_parser_free(ptr, 1));
+const SpanFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_span_free(ptr, 1));
+const StyleFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_style_free(ptr, 1));
+const TestFailureFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_testfailure_free(ptr, 1));
+const TestReportFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_testreport_free(ptr, 1));
+const TraceStepFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_tracestep_free(ptr, 1));
+
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_externrefs.set(idx, obj);
+    return idx;
 }
 
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
+    }
+}
 
-const lTextDecoder = typeof TextDecoder === 'undefined' ? (0, module.require)('util').TextDecoder : TextDecoder;
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(wasm.__wbindgen_externrefs.get(mem.getUint32(i, true)));
+    }
+    wasm.__externref_drop_slice(ptr, len);
+    return result;
+}
 
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
+        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+    }
+    return cachedDataViewMemory0;
+}
 
-cachedTextDecoder.decode();
+function getStringFromWasm0(ptr, len) {
+    return decodeText(ptr >>> 0, len);
+}
 
 let cachedUint8ArrayMemory0 = null;
-
 function getUint8ArrayMemory0() {
     if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
         cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
@@ -19,32 +1292,21 @@ function getUint8ArrayMemory0() {
     return cachedUint8ArrayMemory0;
 }
 
-function getStringFromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
+function isLikeNone(x) {
+    return x === undefined || x === null;
 }
 
-let WASM_VECTOR_LEN = 0;
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? (0, module.require)('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
+function passArrayJsValueToWasm0(array, malloc) {
+    const ptr = malloc(array.length * 4, 4) >>> 0;
+    for (let i = 0; i < array.length; i++) {
+        const add = addToExternrefTable0(array[i]);
+        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);
+    }
+    WASM_VECTOR_LEN = array.length;
+    return ptr;
 }
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
 
 function passStringToWasm0(arg, malloc, realloc) {
-
     if (realloc === undefined) {
         const buf = cachedTextEncoder.encode(arg);
         const ptr = malloc(buf.length, 1) >>> 0;
@@ -65,14 +1327,13 @@ function passStringToWasm0(arg, malloc, realloc) {
         if (code > 0x7F) break;
         mem[ptr + offset] = code;
     }
-
     if (offset !== len) {
         if (offset !== 0) {
             arg = arg.slice(offset);
         }
         ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
         const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
+        const ret = cachedTextEncoder.encodeInto(arg, view);
 
         offset += ret.written;
         ptr = realloc(ptr, len, offset, 1) >>> 0;
@@ -82,141 +1343,43 @@ function passStringToWasm0(arg, malloc, realloc) {
     return ptr;
 }
 
-let cachedDataViewMemory0 = null;
-
-function getDataViewMemory0() {
-    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
-        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
-    }
-    return cachedDataViewMemory0;
+function takeFromExternrefTable0(idx) {
+    const value = wasm.__wbindgen_externrefs.get(idx);
+    wasm.__externref_table_dealloc(idx);
+    return value;
 }
 
-function _assertClass(instance, klass) {
-    if (!(instance instanceof klass)) {
-        throw new Error(`expected instance of ${klass.name}`);
-    }
-}
-/**
- * @param {string} expression
- * @param {Parser} parser
- * @returns {string}
- */
-export function simplify(expression, parser) {
-    let deferred2_0;
-    let deferred2_1;
-    try {
-        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-        const len0 = WASM_VECTOR_LEN;
-        _assertClass(parser, Parser);
-        const ret = wasm.simplify(ptr0, len0, parser.__wbg_ptr);
-        deferred2_0 = ret[0];
-        deferred2_1 = ret[1];
-        return getStringFromWasm0(ret[0], ret[1]);
-    } finally {
-        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+cachedTextDecoder.decode();
+const MAX_SAFARI_DECODE_BYTES = 2146435072;
+let numBytesDecoded = 0;
+function decodeText(ptr, len) {
+    numBytesDecoded += len;
+    if (numBytesDecoded >= MAX_SAFARI_DECODE_BYTES) {
+        cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+        cachedTextDecoder.decode();
+        numBytesDecoded = len;
     }
+    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
 
-const ParserFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_parser_free(ptr >>> 0, 1));
-
-export class Parser {
-
-    static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(Parser.prototype);
-        obj.__wbg_ptr = ptr;
-        ParserFinalization.register(obj, obj.__wbg_ptr, obj);
-        return obj;
-    }
-
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        ParserFinalization.unregister(this);
-        return ptr;
-    }
+const cachedTextEncoder = new TextEncoder();
 
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_parser_free(ptr, 0);
-    }
-    /**
-     * @returns {Parser}
-     */
-    static new() {
-        const ret = wasm.parser_new();
-        return Parser.__wrap(ret);
-    }
-    /**
-     * @param {string} name
-     * @param {string} expression
-     * @returns {string}
-     */
-    create_keyword(name, expression) {
-        let deferred3_0;
-        let deferred3_1;
-        try {
-            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-            const len0 = WASM_VECTOR_LEN;
-            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-            const len1 = WASM_VECTOR_LEN;
-            const ret = wasm.parser_create_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);
-            deferred3_0 = ret[0];
-            deferred3_1 = ret[1];
-            return getStringFromWasm0(ret[0], ret[1]);
-        } finally {
-            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
-        }
-    }
-    /**
-     * @param {string} name
-     */
-    remove_keyword(name) {
-        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.parser_remove_keyword(this.__wbg_ptr, ptr0, len0);
-    }
+if (!('encodeInto' in cachedTextEncoder)) {
+    cachedTextEncoder.encodeInto = function (arg, view) {
+        const buf = cachedTextEncoder.encode(arg);
+        view.set(buf);
+        return {
+            read: arg.length,
+            written: buf.length
+        };
+    };
 }
 
-export function __wbg_error_7534b8e9a36f1ab4(arg0, arg1) {
-    let deferred0_0;
-    let deferred0_1;
-    try {
-        deferred0_0 = arg0;
-        deferred0_1 = arg1;
-        console.error(getStringFromWasm0(arg0, arg1));
-    } finally {
-        wasm.__wbindgen_free(deferred0_0, deferred0_1, 1);
-    }
-};
-
-export function __wbg_new_8a6f238a6ece86ea() {
-    const ret = new Error();
-    return ret;
-};
-
-export function __wbg_stack_0ed75d68575b0f3c(arg0, arg1) {
-    const ret = arg1.stack;
-    const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    const len1 = WASM_VECTOR_LEN;
-    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
-    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-};
-
-export function __wbindgen_init_externref_table() {
-    const table = wasm.__wbindgen_export_3;
-    const offset = table.grow(4);
-    table.set(0, undefined);
-    table.set(offset + 0, undefined);
-    table.set(offset + 1, null);
-    table.set(offset + 2, true);
-    table.set(offset + 3, false);
-    ;
-};
+let WASM_VECTOR_LEN = 0;
 
-export function __wbindgen_throw(arg0, arg1) {
-    throw new Error(getStringFromWasm0(arg0, arg1));
-};
 
+let wasm;
+export function __wbg_set_wasm(val) {
+    wasm = val;
+}
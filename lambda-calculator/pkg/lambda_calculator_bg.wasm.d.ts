@@ -1,13 +1,117 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_budget_free: (a: number, b: number) => void;
+export const __wbg_errorreport_free: (a: number, b: number) => void;
+export const __wbg_evalresult_free: (a: number, b: number) => void;
+export const __wbg_get_errorreport_kind: (a: number) => number;
+export const __wbg_get_errorreport_message: (a: number) => [number, number];
+export const __wbg_get_errorreport_span: (a: number) => number;
+export const __wbg_get_evalresult_error_kind: (a: number) => number;
+export const __wbg_get_evalresult_output: (a: number) => [number, number];
+export const __wbg_get_evalresult_size: (a: number) => number;
+export const __wbg_get_evalresult_span: (a: number) => number;
+export const __wbg_get_evalresult_status: (a: number) => number;
+export const __wbg_get_evalresult_steps: (a: number) => number;
+export const __wbg_get_lineresult_line: (a: number) => number;
+export const __wbg_get_lineresult_name: (a: number) => [number, number];
+export const __wbg_get_lineresult_passed: (a: number) => number;
+export const __wbg_get_lineresult_result: (a: number) => number;
+export const __wbg_get_testfailure_line: (a: number) => number;
+export const __wbg_get_testfailure_message: (a: number) => [number, number];
+export const __wbg_get_testfailure_span: (a: number) => number;
+export const __wbg_get_testreport_failed: (a: number) => number;
+export const __wbg_get_testreport_failures: (a: number) => [number, number];
+export const __wbg_get_testreport_passed: (a: number) => number;
+export const __wbg_get_tracestep_failure: (a: number) => number;
+export const __wbg_get_tracestep_redex: (a: number) => [number, number];
+export const __wbg_get_tracestep_term: (a: number) => [number, number];
+export const __wbg_lineresult_free: (a: number, b: number) => void;
 export const __wbg_parser_free: (a: number, b: number) => void;
-export const parser_new: () => number;
+export const __wbg_set_errorreport_kind: (a: number, b: number) => void;
+export const __wbg_set_errorreport_message: (a: number, b: number, c: number) => void;
+export const __wbg_set_errorreport_span: (a: number, b: number) => void;
+export const __wbg_set_evalresult_error_kind: (a: number, b: number) => void;
+export const __wbg_set_evalresult_output: (a: number, b: number, c: number) => void;
+export const __wbg_set_evalresult_size: (a: number, b: number) => void;
+export const __wbg_set_evalresult_span: (a: number, b: number) => void;
+export const __wbg_set_evalresult_status: (a: number, b: number) => void;
+export const __wbg_set_evalresult_steps: (a: number, b: number) => void;
+export const __wbg_set_lineresult_line: (a: number, b: number) => void;
+export const __wbg_set_lineresult_name: (a: number, b: number, c: number) => void;
+export const __wbg_set_lineresult_passed: (a: number, b: number) => void;
+export const __wbg_set_lineresult_result: (a: number, b: number) => void;
+export const __wbg_set_testfailure_line: (a: number, b: number) => void;
+export const __wbg_set_testfailure_message: (a: number, b: number, c: number) => void;
+export const __wbg_set_testfailure_span: (a: number, b: number) => void;
+export const __wbg_set_testreport_failed: (a: number, b: number) => void;
+export const __wbg_set_testreport_failures: (a: number, b: number, c: number) => void;
+export const __wbg_set_testreport_passed: (a: number, b: number) => void;
+export const __wbg_set_tracestep_failure: (a: number, b: number) => void;
+export const __wbg_set_tracestep_redex: (a: number, b: number, c: number) => void;
+export const __wbg_set_tracestep_term: (a: number, b: number, c: number) => void;
+export const __wbg_span_free: (a: number, b: number) => void;
+export const __wbg_style_free: (a: number, b: number) => void;
+export const __wbg_testfailure_free: (a: number, b: number) => void;
+export const __wbg_testreport_free: (a: number, b: number) => void;
+export const __wbg_tracestep_free: (a: number, b: number) => void;
+export const budget_max_size: (a: number) => number;
+export const budget_max_steps: (a: number) => number;
+export const budget_max_time: (a: number) => [number, number];
+export const budget_new: () => number;
+export const budget_set_max_size: (a: number, b: number) => void;
+export const budget_set_max_steps: (a: number, b: number) => void;
+export const budget_set_max_time: (a: number, b: number, c: number) => void;
+export const budget_unlimited: () => number;
+export const equivalent: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const evaluate: (a: number, b: number, c: number, d: number) => number;
+export const evaluate_within: (a: number, b: number, c: number, d: number, e: number) => number;
+export const parser_arithmetic: (a: number) => number;
+export const parser_budget: (a: number) => number;
 export const parser_create_keyword: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const parser_define_keyword: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const parser_define_keyword_group: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const parser_dependents_of: (a: number, b: number, c: number) => [number, number];
+export const parser_eta: (a: number) => number;
+export const parser_keyword_expression: (a: number, b: number, c: number) => [number, number];
+export const parser_keyword_names: (a: number) => [number, number];
+export const parser_load_program: (a: number, b: number, c: number) => [number, number];
+export const parser_load_program_with: (a: number, b: number, c: number, d: number) => [number, number];
+export const parser_new: () => number;
 export const parser_remove_keyword: (a: number, b: number, c: number) => void;
+export const parser_run_tests: (a: number, b: number, c: number) => number;
+export const parser_run_tests_with: (a: number, b: number, c: number, d: number) => number;
+export const parser_set_arithmetic: (a: number, b: number) => void;
+export const parser_set_budget: (a: number, b: number) => void;
+export const parser_set_eta: (a: number, b: number) => void;
+export const parser_set_style: (a: number, b: number) => void;
+export const parser_style: (a: number) => number;
+export const parser_with_prelude: () => number;
 export const simplify: (a: number, b: number, c: number) => [number, number];
-export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const simplify_with: (a: number, b: number, c: number, d: number) => [number, number];
+export const simplify_within: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const span_end: (a: number) => number;
+export const span_start: (a: number) => number;
+export const style_ascii: (a: number) => number;
+export const style_compress_binders: (a: number) => number;
+export const style_decode: (a: number) => number;
+export const style_fold_keywords: (a: number) => number;
+export const style_minimal_parens: (a: number) => number;
+export const style_new: () => number;
+export const style_set_ascii: (a: number, b: number) => void;
+export const style_set_compress_binders: (a: number, b: number) => void;
+export const style_set_decode: (a: number, b: number) => void;
+export const style_set_fold_keywords: (a: number, b: number) => void;
+export const style_set_minimal_parens: (a: number, b: number) => void;
+export const style_set_width: (a: number, b: number) => void;
+export const style_width: (a: number) => number;
+export const testreport_summary: (a: number) => [number, number];
+export const trace: (a: number, b: number, c: number, d: number) => [number, number];
 export const __wbindgen_malloc: (a: number, b: number) => number;
 export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
-export const __wbindgen_export_3: WebAssembly.Table;
+export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __wbindgen_externrefs: WebAssembly.Table;
+export const __externref_table_dealloc: (a: number) => void;
+export const __externref_drop_slice: (a: number, b: number) => void;
+export const __externref_table_alloc: () => number;
 export const __wbindgen_start: () => void;
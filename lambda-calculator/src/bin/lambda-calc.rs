@@ -0,0 +1,176 @@
+// -- Imports -- //
+
+use lambda_calculator::{evaluate, trace, EvalResult, Parser, Status, Strategy};
+
+use std::io::{IsTerminal, Read};
+use std::process::ExitCode;
+
+// -- Usage -- //
+
+const USAGE: &str = "\
+usage: lambda-calc [options] [expression]
+
+Evaluates the expression, or the standard input when no expression is given.
+
+options:
+  -p, --prelude            start with the keywords of the standard prelude
+  -l, --load <file>        run a program of definitions, evaluations and assertions first
+  -s, --strategy <name>    normal, applicative, call-by-name or call-by-value
+  -t, --trace              print every reduction step before the result
+  -n, --max-steps <n>      stop after n beta reductions, or never with \"none\"
+  -m, --minimal-parens     only print the parentheses that the terms need
+  -w, --width <n>          break terms that are longer than n characters across lines
+  -h, --help               print this message
+
+exit codes:
+  0   the expression was reduced to a normal form
+  1   the expression or a loaded file has an error, or an assertion failed
+  2   the budget ran out before a normal form was found
+  3   the strategy loops without reaching a normal form
+  64  the arguments are invalid or a file can't be read";
+
+const USAGE_ERROR: u8 = 64;
+
+// -- Options -- //
+
+#[derive(Debug, Default)]
+struct Options {
+	prelude: bool,
+	files: Vec<String>,
+	strategy: Strategy,
+	trace: bool,
+	max_steps: Option<Option<usize>>,
+	minimal_parens: bool,
+	width: Option<usize>,
+	expression: Option<String>,
+}
+
+// DOCS Returns `None` when the usage should be printed instead. Everything after the options
+// 		is the expression, and `--` ends the options early for expressions starting with `-`.
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+	let mut options = Options::default();
+	let mut words = Vec::new();
+	
+	while let Some(arg) = args.next() {
+		let mut value = |name: &str| args.next().ok_or(format!("{name} expects a value"));
+		
+		match arg.as_str() {
+			"-h" | "--help" => return Ok(None),
+			"-p" | "--prelude" => options.prelude = true,
+			"-l" | "--load" => options.files.push(value(&arg)?),
+			"-s" | "--strategy" => options.strategy = value(&arg)?.parse()?,
+			"-t" | "--trace" => options.trace = true,
+			"-n" | "--max-steps" => options.max_steps = Some(match value(&arg)?.as_str() {
+				"none" => None,
+				n => Some(n.parse().map_err(|_| format!("\"{n}\" is not a number of steps"))?),
+			}),
+			
+			"-m" | "--minimal-parens" => options.minimal_parens = true,
+			"-w" | "--width" => {
+				let n = value(&arg)?;
+				options.width = Some(n.parse().map_err(|_| format!("\"{n}\" is not a width"))?);
+			}
+			
+			"--" => {
+				words.extend(args);
+				break
+			}
+			
+			option if option.starts_with('-') && words.is_empty() => return Err(format!("unknown option \"{option}\"")),
+			_ => words.push(arg),
+		}
+	}
+	
+	if !words.is_empty() { options.expression = Some(words.join(" ")) }
+	
+	Ok(Some(options))
+}
+
+fn exit_code(status: Status) -> ExitCode {
+	match status {
+		Status::NormalForm => ExitCode::SUCCESS,
+		Status::Error => ExitCode::from(1),
+		Status::BudgetExhausted => ExitCode::from(2),
+		Status::Diverged => ExitCode::from(3),
+	}
+}
+
+fn report(result: &EvalResult, source: &str) -> ExitCode {
+	match result.status {
+		Status::NormalForm => println!("{}", result.output),
+		_ => eprintln!("{}", result.underline(source)),
+	}
+	
+	exit_code(result.status)
+}
+
+// -- Main -- //
+
+fn main() -> ExitCode {
+	let options = match parse_options(std::env::args().skip(1)) {
+		Ok(Some(options)) => options,
+		Ok(None) => {
+			println!("{USAGE}");
+			return ExitCode::SUCCESS
+		}
+		Err(message) => {
+			eprintln!("lambda-calc: {message}\n\n{USAGE}");
+			return ExitCode::from(USAGE_ERROR)
+		}
+	};
+	
+	let mut parser = if options.prelude { Parser::with_prelude() } else { Parser::new() };
+	
+	if let Some(max_steps) = options.max_steps {
+		let mut budget = parser.budget();
+		budget.set_max_steps(max_steps);
+		parser.set_budget(&budget);
+	}
+	
+	let mut style = parser.style();
+	style.set_minimal_parens(options.minimal_parens);
+	style.set_width(options.width);
+	parser.set_style(&style);
+	
+	// Files stop at their first failing line, and only print the results of evaluations.
+	for path in &options.files {
+		let Ok(text) = std::fs::read_to_string(path) else {
+			eprintln!("lambda-calc: can't read \"{path}\"");
+			return ExitCode::from(USAGE_ERROR)
+		};
+		
+		for line in parser.load_program_with(text.clone(), options.strategy) {
+			if line.result.status != Status::NormalForm {
+				eprintln!("{path}:{}: {}", line.line, line.result.underline(&text));
+				return exit_code(line.result.status)
+			}
+			
+			if line.name.is_none() && line.passed.is_none() { println!("{}", line.result.output) }
+		}
+	}
+	
+	let expression = match options.expression {
+		Some(expression) => expression,
+		None if !options.files.is_empty() && std::io::stdin().is_terminal() => return ExitCode::SUCCESS,
+		None => {
+			let mut input = String::new();
+			if std::io::stdin().read_to_string(&mut input).is_err() {
+				eprintln!("lambda-calc: can't read the standard input");
+				return ExitCode::from(USAGE_ERROR)
+			}
+			
+			input
+		}
+	};
+	
+	if expression.trim().is_empty() && !options.files.is_empty() { return ExitCode::SUCCESS }
+	
+	if options.trace {
+		for step in trace(expression.clone(), &parser, options.strategy) {
+			if step.failure.is_some() { break }
+			println!("{}    [{}]", step.term, step.redex);
+		}
+	}
+	
+	report(&evaluate(expression.clone(), &parser, options.strategy), &expression)
+}
@@ -0,0 +1,229 @@
+// -- Imports -- //
+
+use lambda_calculator::{trace, Parser, Status, Strategy};
+
+use std::io::{BufRead, Write};
+
+// -- Help -- //
+
+const USAGE: &str = "\
+usage: lambda-repl [-p | --prelude] [file ...]
+
+Loads the files as programs, then reads expressions and commands until the end of the input.";
+
+const HELP: &str = "\
+expressions are evaluated, and lines like \"name = expression\" or \"assert a == b\" work like
+they do in program files.
+
+commands:
+  :def <name> = <expression>    define a keyword
+  :undef <name>                 remove a keyword
+  :list                         list every keyword with its expression
+  :load <file>                  run a program file
+  :trace [on | off]             print every reduction step of an evaluation
+  :strategy [name]              show or set the strategy
+  :steps [n | none]             show or set the step limit
+  :parens [minimal | full]      show or set which parentheses are printed
+  :width [n | none]             show or set the width that long terms are broken at
+  :history                      list the previous inputs
+  :redo [n]                     run input n again, or the last input
+  :help                         print this message
+  :quit                         leave";
+
+// -- Session -- //
+
+struct Session {
+	parser: Parser,
+	strategy: Strategy,
+	trace: bool,
+	history: Vec<String>,
+}
+
+impl Session {
+	// DOCS Returns `false` once the session should end.
+	fn run(&mut self, input: &str) -> bool {
+		let input = input.trim();
+		if input.is_empty() { return true }
+		
+		let (command, argument) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+		let argument = argument.trim();
+		
+		if !matches!(command, ":history" | ":redo") { self.history.push(input.to_string()) }
+		
+		match command {
+			":quit" | ":q" => return false,
+			":help" | ":h" => println!("{HELP}"),
+			":def" => match split_definition(argument) {
+				Some((name, expression)) => match self.parser.define(name, expression) {
+					Ok(()) => println!("{} = {}", name.trim(), expression.trim()),
+					Err(e) => println!("{}", e.underline(expression)),
+				},
+				None => println!("expected \":def <name> = <expression>\""),
+			},
+			
+			":undef" => {
+				if self.parser.keyword_expression(argument.to_string()).is_none() {
+					println!("\"{argument}\" isn't defined");
+					return true
+				}
+				
+				let dependents = self.parser.dependents_of(argument.to_string());
+				self.parser.remove_keyword(argument.to_string());
+				
+				if !dependents.is_empty() { println!("still used by {}", dependents.join(", ")) }
+			}
+			
+			":list" => for name in self.parser.keyword_names() {
+				println!("{name} = {}", self.parser.keyword_expression(name.clone()).unwrap_or_default());
+			},
+			
+			":load" => match std::fs::read_to_string(argument) {
+				Ok(text) => self.load(&text, Some(argument)),
+				Err(e) => println!("can't read \"{argument}\": {e}"),
+			},
+			
+			":trace" => match argument {
+				"" => println!("trace is {}", if self.trace { "on" } else { "off" }),
+				"on" => self.trace = true,
+				"off" => self.trace = false,
+				_ => println!("expected \":trace on\" or \":trace off\""),
+			},
+			
+			":strategy" => match argument {
+				"" => println!("{}", self.strategy),
+				name => match name.parse() {
+					Ok(strategy) => self.strategy = strategy,
+					Err(message) => println!("{message}"),
+				},
+			},
+			
+			":steps" => {
+				let mut budget = self.parser.budget();
+				
+				match argument {
+					"" => println!("{}", budget.max_steps().map_or("none".to_string(), |n| n.to_string())),
+					"none" => budget.set_max_steps(None),
+					n => match n.parse() {
+						Ok(n) => budget.set_max_steps(Some(n)),
+						Err(_) => println!("\"{n}\" is not a number of steps"),
+					},
+				}
+				
+				self.parser.set_budget(&budget);
+			}
+			
+			":parens" => {
+				let mut style = self.parser.style();
+				
+				match argument {
+					"" => println!("{}", if style.minimal_parens() { "minimal" } else { "full" }),
+					"minimal" => style.set_minimal_parens(true),
+					"full" => style.set_minimal_parens(false),
+					_ => println!("expected \":parens minimal\" or \":parens full\""),
+				}
+				
+				self.parser.set_style(&style);
+			}
+			
+			":width" => {
+				let mut style = self.parser.style();
+				
+				match argument {
+					"" => println!("{}", style.width().map_or("none".to_string(), |n| n.to_string())),
+					"none" => style.set_width(None),
+					n => match n.parse() {
+						Ok(n) => style.set_width(Some(n)),
+						Err(_) => println!("\"{n}\" is not a width"),
+					},
+				}
+				
+				self.parser.set_style(&style);
+			}
+			
+			":history" => for (index, line) in self.history.iter().enumerate() {
+				println!("{:>4}  {line}", index + 1);
+			},
+			
+			":redo" => {
+				let index = if argument.is_empty() { Some(self.history.len()) } else { argument.parse::<usize>().ok() };
+				
+				match index.and_then(|n| n.checked_sub(1)).and_then(|n| self.history.get(n)).cloned() {
+					Some(line) => {
+						println!("{line}");
+						return self.run(&line)
+					}
+					None => println!("there's no input \"{argument}\" in the history"),
+				}
+			}
+			
+			command if command.starts_with(':') => println!("unknown command \"{command}\", see :help"),
+			_ => self.load(input, None),
+		}
+		
+		true
+	}
+	
+	// DOCS Prints the results of evaluations, and the errors of every other line. Each line is
+	// 		printed while the program loads, so a trace only sees the keywords above it.
+	fn load(&mut self, text: &str, path: Option<&str>) {
+		let (strategy, tracing) = (self.strategy, self.trace);
+		
+		self.parser.load_program_each(text.to_string(), strategy, |parser, line, evaluated| {
+			if let Some(expression) = evaluated.filter(|_| tracing) {
+				for step in trace(expression.to_string(), parser, strategy) {
+					if step.failure.is_some() { break }
+					println!("  {}    [{}]", step.term, step.redex);
+				}
+			}
+			
+			match (line.result.status, path) {
+				(Status::NormalForm, _) => if evaluated.is_some() { println!("{}", line.result.output) },
+				(_, Some(path)) => println!("{path}:{}: {}", line.line, line.result.underline(text)),
+				(_, None) => println!("{}", line.result.underline(text)),
+			}
+		});
+	}
+}
+
+// DOCS Splits at the first `=` with whitespace on both sides, so that names like `==` can be
+// 		defined.
+fn split_definition(argument: &str) -> Option<(&str, &str)> {
+	let (index, _) = argument.char_indices().find(|&(i, c)| {
+		c == '=' && argument[..i].ends_with(char::is_whitespace) && argument[i + 1..].starts_with(char::is_whitespace)
+	})?;
+	
+	Some((&argument[..index], &argument[index + 1..]))
+}
+
+// -- Main -- //
+
+fn main() {
+	let mut parser = Parser::new();
+	let mut files = Vec::new();
+	
+	for arg in std::env::args().skip(1) {
+		match arg.as_str() {
+			"-p" | "--prelude" => parser = Parser::with_prelude(),
+			"-h" | "--help" => return println!("{USAGE}"),
+			_ => files.push(arg),
+		}
+	}
+	
+	let mut session = Session { parser, strategy: Strategy::default(), trace: false, history: Vec::new() };
+	
+	for path in files { session.run(&format!(":load {path}")); }
+	
+	let stdin = std::io::stdin();
+	let mut lines = stdin.lock().lines();
+	
+	loop {
+		print!("λ> ");
+		if std::io::stdout().flush().is_err() { break }
+		
+		let Some(Ok(line)) = lines.next() else {
+			println!();
+			break
+		};
+		if !session.run(&line) { break }
+	}
+}
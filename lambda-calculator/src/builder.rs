@@ -1,7 +1,7 @@
 // -- Imports -- //
 
 use crate::objects::{Object, Expr};
-use crate::errors::{LambdaError, Result};
+use crate::errors::{LambdaError, Result, Span};
 use crate::naming::{CopyID, NameID, NameSpace};
 use crate::parser::{Token, KEYWORD_VARIABLE_TEST};
 
@@ -10,14 +10,14 @@ use std::collections::{HashMap, VecDeque};
 
 // -- Exports -- //
 
-pub fn build(tokens: Vec<Token>) -> Result<Expr> {
+pub fn build(tokens: Vec<(Token, Span)>) -> Result<Expr> {
 	let mut b = Builder {
 		level: 0,
 		names: HashMap::new(),
 		listings: Vec::new(),
 	};
 	
-	if tokens.len() == 0 { return Err(LambdaError::InternalFailure) }
+	if tokens.is_empty() { return Err(LambdaError::InternalFailure) }
 	let mut iter: TokenStream = tokens.into_iter();
 	
 	let object = b.parse_closure(&mut iter, None)?;
@@ -41,7 +41,7 @@ pub fn build(tokens: Vec<Token>) -> Result<Expr> {
 
 // -- Typing -- //
 
-type TokenStream = std::vec::IntoIter<Token>;
+type TokenStream = std::vec::IntoIter<(Token, Span)>;
 
 #[derive(Copy, Clone, Debug)]
 enum Name {
@@ -67,12 +67,12 @@ impl Builder {
 		
 		if let Some(name) = name { self.add_func_name(&mut vars, name)? }
 		
-		let mut token = 'func_vars: loop {
+		let (mut token, mut span) = 'func_vars: loop {
 			match iter.next() {
-				None | Some(Token::Close) =>
+				None | Some((Token::Close, _)) =>
 					return self.end_closure(vars.clone(), VecDeque::new()),
 				
-				Some(Token::Func(name)) =>
+				Some((Token::Func(name), _)) =>
 					self.add_func_name(&mut vars, name)?,
 				
 				Some(tk) =>
@@ -86,22 +86,33 @@ impl Builder {
 			match token {
 				Token::Open =>
 					inner.push_back(self.parse_closure(iter, None)?),
-				Token::Func(name) =>
-					inner.push_back(self.parse_closure(iter, Some(name))?),
+				// A function extends to the end of the closure that it's in, and uses up its close.
+				Token::Func(name) => {
+					inner.push_back(self.parse_closure(iter, Some(name))?);
+					break 'func_inner
+				}
 				Token::Var(name) =>
-					inner.push_back(self.make_variable(name)?),
+					inner.push_back(self.make_variable(name, span)?),
+				Token::Num(value) =>
+					inner.push_back(Object::Num { value }),
+				Token::Int(value) =>
+					inner.push_back(Object::Int { value }),
+				Token::Prim(op) =>
+					inner.push_back(Object::Prim { op }),
+				Token::Keyword(_) | Token::Let(_) | Token::In =>
+					return Err(LambdaError::InternalFailure),
 				Token::Close =>
 					break 'func_inner,
 			}
 			
-			token = if let Some(tk) = iter.next() { tk }
+			(token, span) = if let Some(tk) = iter.next() { tk }
 			else { break 'func_inner };
 		}
 		
 		self.end_closure(vars, inner)
 	}
 	
-	fn make_variable(&mut self, name: String) -> Result<Object> {
+	fn make_variable(&mut self, name: String, span: Span) -> Result<Object> {
 		match self.names.get(&name) {
 			Some(Name::Term { name_id }) =>
 				Ok(Object::Term { name: *name_id }),
@@ -110,7 +121,7 @@ impl Builder {
 				Ok(Object::Var { depth: self.level - *level, queue_position: *queue_position }),
 			
 			Some(Name::DeadVar { .. }) =>
-				Err(LambdaError::UnavailableName(name)),
+				Err(LambdaError::UnavailableName(name, span)),
 			
 			None => {
 				let out = Object::Term { name: self.listings.len() };
@@ -200,3 +211,31 @@ impl Builder {
 		remap
 	}
 }
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use crate::parser::Parser;
+	use crate::term::Term;
+	
+	fn var(name: &str) -> Term { Term::var(name) }
+	
+	#[test]
+	fn nested_function_ends_with_its_closure() {
+		let parser = Parser::new();
+		
+		assert_eq!(
+			Term::parse("(λy.a λx.x) b", &parser).unwrap(),
+			Term::app(Term::lam("y", Term::app(var("a"), Term::lam("x", var("x")))), var("b")),
+		);
+		assert_eq!(
+			Term::parse("a (b λy.c) d", &parser).unwrap(),
+			Term::app(Term::app(var("a"), Term::app(var("b"), Term::lam("y", var("c")))), var("d")),
+		);
+		assert_eq!(
+			Term::parse("λx.x λy.y z", &parser).unwrap(),
+			Term::lam("x", Term::app(var("x"), Term::lam("y", Term::app(var("y"), var("z"))))),
+		);
+	}
+}
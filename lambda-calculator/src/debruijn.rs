@@ -0,0 +1,171 @@
+// -- Imports -- //
+
+use crate::delta::Primitive;
+use crate::naming::NameSpace;
+use crate::objects::{Expr, Object};
+
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// -- Typing -- //
+
+// DOCS A name-free form of an expression where every function has a single variable, so two
+// 		expressions have equal forms exactly when they are alpha-equivalent. Free terms are
+// 		kept by name, which lets expressions built by separate `NameSpace`s be compared, and
+// 		Church numerals are always kept as numbers, whether or not they were unfolded.
+// 		Applications of a variable or a free term are counted, so `f (f x)` is `Iterate(2, f, x)`
+// 		whether it's written out or was folded into `2 f x` by `tidy_closure`. A numeral that
+// 		is a real function is never counted, since applying it is still a redex.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum DeBruijn {
+	Func(Box<DeBruijn>),
+	App(Box<DeBruijn>, Box<DeBruijn>),
+	Var(usize),
+	Term(String),
+	Num(usize),
+	Iterate(usize, Box<DeBruijn>, Box<DeBruijn>),
+	Int(i64),
+	Prim(Primitive),
+	Empty,
+}
+
+// -- Exports -- //
+
+impl Expr {
+	pub fn de_bruijn(&self) -> DeBruijn { convert(&self.object, &self.name_space, &mut Vec::new()) }
+}
+
+impl DeBruijn {
+	pub fn fingerprint(&self) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		self.hash(&mut hasher);
+		hasher.finish()
+	}
+	
+	pub fn church_true() -> Self { Self::Func(Box::new(Self::Func(Box::new(Self::Var(1))))) }
+	pub fn church_false() -> Self { Self::Num(0) }
+	
+	// DOCS Church numerals have the form `λf.λx.f (f ... (f x))`, which is counted as `f`
+	// 		applied to `x` some number of times.
+	fn as_numeral(&self) -> Option<usize> {
+		let Self::Func(f) = self else { return None };
+		let Self::Func(body) = f.as_ref() else { return None };
+		
+		match body.as_ref() {
+			Self::Var(0) => Some(0),
+			Self::Iterate(count, f, x) if **f == Self::Var(1) && **x == Self::Var(0) => Some(*count),
+			_ => None,
+		}
+	}
+	
+	fn is_atom(&self) -> bool { matches!(self, Self::Var(_) | Self::Term(_)) }
+	
+	// DOCS Applies `f` to `x` `count` more times, adding to the count if `x` already applies `f`.
+	fn iterate(count: usize, f: Self, x: Self) -> Self {
+		match x {
+			Self::Iterate(applied, g, x) if *g == f => Self::Iterate(count + applied, g, x),
+			x => Self::Iterate(count, Box::new(f), Box::new(x)),
+		}
+	}
+	
+	fn apply(f: Self, x: Self) -> Self {
+		if f.is_atom() { Self::iterate(1, f, x) }
+		else { Self::App(Box::new(f), Box::new(x)) }
+	}
+}
+
+// -- Conversion -- //
+
+// DOCS `levels` holds the number of variables of every function enclosing the object,
+// 		with the innermost function last.
+pub(crate) fn convert(object: &Object, name_space: &NameSpace, levels: &mut Vec<usize>) -> DeBruijn {
+	match object {
+		Object::Term { name } =>
+			DeBruijn::Term(name_space[name_space.get_absolute_name_index(*name)].clone()),
+		
+		Object::Var { depth, queue_position } => {
+			let inner_count = levels.iter().rev().take(*depth).sum::<usize>();
+			let level = levels[levels.len() - 1 - depth];
+			
+			DeBruijn::Var(inner_count + level - 1 - queue_position)
+		}
+		
+		Object::Num { value } =>
+			DeBruijn::Num(*value),
+		
+		Object::Int { value } =>
+			DeBruijn::Int(*value),
+		
+		Object::Prim { op } =>
+			DeBruijn::Prim(*op),
+		
+		Object::Func { vars, inner } => {
+			levels.push(vars.len());
+			let body = convert_application(inner, name_space, levels);
+			levels.pop();
+			
+			(0..vars.len()).fold(body, |o, _| {
+				let o = DeBruijn::Func(Box::new(o));
+				if let Some(value) = o.as_numeral() { DeBruijn::Num(value) }
+				else { o }
+			})
+		}
+	}
+}
+
+fn convert_application(inner: &VecDeque<Object>, name_space: &NameSpace, levels: &mut Vec<usize>) -> DeBruijn {
+	let mut objects = inner.iter();
+	
+	let head = match Object::as_iteration(inner) {
+		Some((count, f, x)) => {
+			objects.nth(2);
+			DeBruijn::iterate(count, convert(f, name_space, levels), convert(x, name_space, levels))
+		}
+		
+		None => match objects.next() {
+			Some(o) => convert(o, name_space, levels),
+			None => return DeBruijn::Empty,
+		},
+	};
+	
+	objects.fold(head, |f, o| DeBruijn::apply(f, convert(o, name_space, levels)))
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use crate::builder;
+	use crate::equivalence::{equivalent, Equivalence};
+	use crate::parser::Parser;
+	
+	use super::*;
+	
+	fn form(expression: &str) -> DeBruijn {
+		builder::build(Parser::new().tokenize(expression.to_string()).unwrap()).unwrap().de_bruijn()
+	}
+	
+	#[test]
+	fn counted_numerals_are_numerals() {
+		assert_eq!(form("λf.λx.2 f x"), DeBruijn::Num(2));
+		assert_eq!(form("λf.λx.f (f (f x))"), DeBruijn::Num(3));
+		assert_eq!(form("λf.λx.f (2 f x)"), DeBruijn::Num(3));
+		assert_eq!(form("λa.λb.b"), DeBruijn::Num(0));
+	}
+	
+	#[test]
+	fn counted_applications_are_written_out() {
+		assert_eq!(form("λv.2 c (λx.x) d"), form("λv.c (c (λx.x)) d"));
+		assert_eq!(form("λf.f (2 f (λx.x))"), form("λf.3 f (λx.x)"));
+		assert_ne!(form("λf.λg.f (g x)"), form("λf.λg.2 f x"));
+	}
+	
+	#[test]
+	fn applied_numerals_are_not_numerals() {
+		let unreduced = "λw.λx.w ((λf.λy.y) w x)";
+		
+		assert_ne!(form(unreduced), form("λw.λx.w x"));
+		assert_ne!(form("λf.λx.f ((λg.λy.g y) f x)"), DeBruijn::Num(2));
+		assert_eq!(equivalent(unreduced.to_string(), "λw.λx.w x".to_string(), &Parser::new()).unwrap(), Equivalence::BetaEquivalent);
+	}
+}
@@ -0,0 +1,255 @@
+// -- Imports -- //
+
+use crate::errors::{LambdaError, Result};
+use crate::naming::NameSpace;
+use crate::objects::Object;
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+// -- Primitives -- //
+
+// DOCS The operators of native arithmetic. Comparisons reduce to the Church booleans
+// 		`λx.λy.x` and `λx.λy.y`, which `if` uses to pick a branch without reducing the other.
+// 		`fix` can't be written, and is only used for recursive keywords in any arithmetic.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Primitive {
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Rem,
+	Eq,
+	Ne,
+	Lt,
+	Gt,
+	Le,
+	Ge,
+	If,
+	Fix,
+}
+
+impl Primitive {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"+" => Some(Self::Add),
+			"-" => Some(Self::Sub),
+			"*" => Some(Self::Mul),
+			"/" => Some(Self::Div),
+			"%" => Some(Self::Rem),
+			"==" => Some(Self::Eq),
+			"!=" => Some(Self::Ne),
+			"<" => Some(Self::Lt),
+			">" => Some(Self::Gt),
+			"<=" => Some(Self::Le),
+			">=" => Some(Self::Ge),
+			"if" => Some(Self::If),
+			_ => None,
+		}
+	}
+	
+	pub fn name(self) -> &'static str {
+		match self {
+			Self::Add => "+",
+			Self::Sub => "-",
+			Self::Mul => "*",
+			Self::Div => "/",
+			Self::Rem => "%",
+			Self::Eq => "==",
+			Self::Ne => "!=",
+			Self::Lt => "<",
+			Self::Gt => ">",
+			Self::Le => "<=",
+			Self::Ge => ">=",
+			Self::If => "if",
+			Self::Fix => "fix",
+		}
+	}
+	
+	// DOCS `fix f` is only unfolded once it's applied to an argument, so it counts that argument.
+	pub fn arity(self) -> usize { if self == Self::If { 3 } else { 2 } }
+	
+	// DOCS `if` only looks at its condition, `fix` doesn't look at anything, and the other
+	// 		primitives look at both of their arguments.
+	fn strict_arguments(self) -> Range<usize> {
+		match self {
+			Self::If => 1..2,
+			Self::Fix => 1..1,
+			_ => 1..3,
+		}
+	}
+	
+	fn compute(self, a: i64, b: i64, name_space: &mut NameSpace) -> Result<Object> {
+		let value = match self {
+			Self::Add => a.checked_add(b),
+			Self::Sub => a.checked_sub(b),
+			Self::Mul => a.checked_mul(b),
+			Self::Div | Self::Rem if b == 0 =>
+				return Err(LambdaError::DivisionByZero(format!("{} {a} {b}", self.name()))),
+			Self::Div => a.checked_div(b),
+			Self::Rem => a.checked_rem(b),
+			
+			Self::Eq => return Ok(Object::boolean(a == b, name_space)),
+			Self::Ne => return Ok(Object::boolean(a != b, name_space)),
+			Self::Lt => return Ok(Object::boolean(a < b, name_space)),
+			Self::Gt => return Ok(Object::boolean(a > b, name_space)),
+			Self::Le => return Ok(Object::boolean(a <= b, name_space)),
+			Self::Ge => return Ok(Object::boolean(a >= b, name_space)),
+			
+			Self::If | Self::Fix => return Err(LambdaError::InternalFailure),
+		};
+		
+		let Some(value) = value else { return Err(LambdaError::IntegerOverflow(format!("{} {a} {b}", self.name()))) };
+		Ok(Object::Int { value })
+	}
+}
+
+// -- Delta Reduction -- //
+
+impl Object {
+	// DOCS A delta redex is a closure headed by a primitive with all of its arguments, where
+	// 		every argument that the primitive looks at is already an integer or a boolean.
+	pub(crate) fn is_delta_redex(&self) -> bool {
+		let Self::Func { vars: _, inner } = self else { return false };
+		let Some(Self::Prim { op }) = inner.front() else { return false };
+		if inner.len() <= op.arity() { return false }
+		
+		match op {
+			Primitive::If => inner[1].as_boolean().is_some(),
+			Primitive::Fix => true,
+			_ => matches!((&inner[1], &inner[2]), (Self::Int { value: _ }, Self::Int { value: _ })),
+		}
+	}
+	
+	// DOCS Primitives need the arguments that they look at to be values, so every strategy
+	// 		reduces those first, even call-by-name, which otherwise never reduces arguments.
+	pub(crate) fn strict_arguments(&self) -> Range<usize> {
+		let Self::Func { vars: _, inner } = self else { return 0..0 };
+		let Some(Self::Prim { op }) = inner.front() else { return 0..0 };
+		
+		if inner.len() <= op.arity() { 0..0 }
+		else { op.strict_arguments() }
+	}
+	
+	pub(crate) fn apply_delta(&mut self, name_space: &mut NameSpace) -> Result<()> {
+		if !self.is_delta_redex() { return Err(LambdaError::InternalFailure) }
+		let Self::Func { vars: _, inner } = self else { return Err(LambdaError::InternalFailure) };
+		let Some(Self::Prim { op }) = inner.pop_front() else { return Err(LambdaError::InternalFailure) };
+		
+		let mut args = inner.drain(..op.arity()).collect::<VecDeque<Object>>();
+		
+		// `fix f a => f (fix f) a`
+		if op == Primitive::Fix {
+			let (Some(f), Some(a)) = (args.pop_front(), args.pop_front()) else { return Err(LambdaError::InternalFailure) };
+			let fixed = Self::Func { vars: VecDeque::new(), inner: VecDeque::from([Self::Prim { op }, f.cloned(name_space, 0, 0)?]) };
+			
+			inner.push_front(a);
+			inner.push_front(fixed);
+			inner.push_front(f);
+			
+			return Ok(())
+		}
+		
+		let out = match (op, &args[0], args.get(1)) {
+			(Primitive::If, condition, _) => {
+				let Some(condition) = condition.as_boolean() else { return Err(LambdaError::InternalFailure) };
+				args.remove(if condition { 1 } else { 2 }).unwrap()
+			}
+			
+			(_, Self::Int { value: a }, Some(Self::Int { value: b })) =>
+				op.compute(*a, *b, name_space)?,
+			
+			_ => return Err(LambdaError::InternalFailure),
+		};
+		
+		inner.push_front(out);
+		
+		Ok(())
+	}
+	
+	fn boolean(value: bool, name_space: &mut NameSpace) -> Self {
+		Self::Func {
+			vars: [name_space.get_or_add_var_name("x"), name_space.get_or_add_var_name("y")]
+				.into_iter()
+				.map(|name_id| (name_id, Cell::new(0)))
+				.collect(),
+			inner: VecDeque::from([Self::Var { depth: 0, queue_position: if value { 0 } else { 1 } }]),
+		}
+	}
+	
+	// DOCS Matches `λx.λy.x` and `λx.λy.y`, whether or not both variables are in one function.
+	fn as_boolean(&self) -> Option<bool> {
+		let Self::Func { vars, inner } = self else { return None };
+		
+		match (vars.len(), inner.len(), inner.front()) {
+			(2, 1, Some(Self::Var { depth: 0, queue_position })) => Some(*queue_position == 0),
+			(1, 1, Some(Self::Func { vars, inner })) if vars.len() == 1 && inner.len() == 1 => {
+				let Some(Self::Var { depth, queue_position: 0 }) = inner.front() else { return None };
+				Some(*depth == 1)
+			}
+			
+			_ => None,
+		}
+	}
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use crate::errors::ErrorKind;
+	use crate::evaluate;
+	use crate::parser::Parser;
+	use crate::reduction::{Arithmetic, Status, Strategy};
+	
+	fn native(expression: &str, strategy: Strategy) -> (Status, Option<ErrorKind>, String) {
+		let mut parser = Parser::new();
+		parser.set_arithmetic(Arithmetic::Native);
+		
+		let result = evaluate(expression.to_string(), &parser, strategy);
+		(result.status, result.error_kind, result.output)
+	}
+	
+	fn value(expression: &str) -> String { native(expression, Strategy::default()).2 }
+	
+	#[test]
+	fn arithmetic_computes_integers() {
+		assert_eq!(value("+ 2 3"), "5");
+		assert_eq!(value("- 2 5"), "-3");
+		assert_eq!(value("* 6 7"), "42");
+		assert_eq!(value("/ 7 2"), "3");
+		assert_eq!(value("% 7 2"), "1");
+		assert_eq!(value("+ a 1"), "+ a 1");
+	}
+	
+	#[test]
+	fn overflow_and_division_by_zero_are_errors() {
+		for expression in ["* 9223372036854775807 2", "- (- 0 9223372036854775807) 2", "/ 1 0", "% 1 0"] {
+			let (status, kind, output) = native(expression, Strategy::default());
+			assert_eq!((status, kind), (Status::Error, Some(ErrorKind::Arithmetic)), "{expression}: {output}");
+		}
+		
+		assert_eq!(value("/ 1 0"), "arithmetic error: \"/ 1 0\" divides by zero");
+	}
+	
+	#[test]
+	fn primitive_arguments_are_reduced_by_every_strategy() {
+		for strategy in [Strategy::Normal, Strategy::Applicative, Strategy::CallByName, Strategy::CallByValue] {
+			assert_eq!(native("+ ((λx.x) 2) ((λy.y) 3)", strategy).2, "5", "{strategy:?}");
+			assert_eq!(native("if (< 1 2) a ((λx.x x) (λx.x x))", strategy).2, "a", "{strategy:?}");
+		}
+	}
+	
+	#[test]
+	fn comparisons_are_church_booleans() {
+		assert_eq!(value("< 1 2"), "λx.λy.x");
+		assert_eq!(value("== 1 2"), "λx.λy.y");
+		assert_eq!(value("!= 1 2"), "λx.λy.x");
+		assert_eq!(value(">= 2 2"), "λx.λy.x");
+		assert_eq!(value("<= 3 2"), "λx.λy.y");
+		assert_eq!(value("> 3 2"), "λx.λy.x");
+		assert_eq!(value("(< 1 2) a b"), "a");
+		assert_eq!(value("if ((λp.λq.p q p) (< 1 2) (> 1 2)) a b"), "b");
+	}
+}
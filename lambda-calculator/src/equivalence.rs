@@ -0,0 +1,108 @@
+// -- Imports -- //
+
+use crate::builder;
+use crate::errors::{LambdaError, Result};
+use crate::objects::Expr;
+use crate::parser::Parser;
+use crate::reduction::Strategy;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// -- Typing -- //
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Equivalence {
+	AlphaEquivalent,
+	BetaEquivalent,
+	Different,
+	Undecided,
+}
+
+// -- Exports -- //
+
+// DOCS Both expressions are reduced in normal order within the parser's budget. Normal order
+// 		finds a normal form whenever there is one, so an expression caught in a cycle differs
+// 		from any expression with a normal form. Anything else that stops a reduction early
+// 		leaves the answer undecided.
+#[wasm_bindgen]
+pub fn equivalent(a: String, b: String, parser: &Parser) -> Result<Equivalence> {
+	let mut a = build(a, parser)?;
+	let mut b = build(b, parser)?;
+	
+	if a.de_bruijn() == b.de_bruijn() { return Ok(Equivalence::AlphaEquivalent) }
+	
+	let a_normal = normalize(&mut a, parser)?;
+	let b_normal = normalize(&mut b, parser)?;
+	
+	match (a_normal, b_normal) {
+		(Some(true), Some(true)) if a.de_bruijn() == b.de_bruijn() => Ok(Equivalence::BetaEquivalent),
+		(Some(a_normal), Some(b_normal)) if a_normal || b_normal => Ok(Equivalence::Different),
+		_ => Ok(Equivalence::Undecided),
+	}
+}
+
+// DOCS Returns whether the expression has a normal form, or `None` if that's still unknown.
+fn normalize(expr: &mut Expr, parser: &Parser) -> Result<Option<bool>> {
+	match expr.reduce(Strategy::Normal, parser.eta(), &parser.budget()) {
+		Ok(()) => Ok(Some(true)),
+		Err(LambdaError::Divergent(_)) => Ok(Some(false)),
+		Err(LambdaError::LimitReached(..) | LambdaError::ExpressionSizeLimit) => Ok(None),
+		Err(e) => Err(e),
+	}
+}
+
+fn build(expression: String, parser: &Parser) -> Result<Expr> {
+	let tokens = parser.tokenize(expression)?;
+	builder::build(tokens)
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::reduction::Budget;
+	
+	fn compare(a: &str, b: &str, parser: &Parser) -> Equivalence {
+		equivalent(a.to_string(), b.to_string(), parser).unwrap()
+	}
+	
+	#[test]
+	fn renamed_variables_are_alpha_equivalent() {
+		let parser = Parser::new();
+		
+		assert_eq!(compare("λx.λy.x y", "λa.λb.a b", &parser), Equivalence::AlphaEquivalent);
+		assert_eq!(compare("(λx.x) a", "(λy.y) a", &parser), Equivalence::AlphaEquivalent);
+	}
+	
+	#[test]
+	fn equal_normal_forms_are_beta_equivalent() {
+		let parser = Parser::with_prelude();
+		
+		assert_eq!(compare("(λx.x) a", "a", &parser), Equivalence::BetaEquivalent);
+		assert_eq!(compare("add 1 2", "3", &parser), Equivalence::BetaEquivalent);
+		assert_eq!(compare("and true false", "false", &parser), Equivalence::BetaEquivalent);
+	}
+	
+	#[test]
+	fn different_normal_forms_and_cycles_are_different() {
+		let parser = Parser::new();
+		
+		assert_eq!(compare("λx.λy.x", "λx.λy.y", &parser), Equivalence::Different);
+		assert_eq!(compare("(λx.x x) (λx.x x)", "a", &parser), Equivalence::Different);
+	}
+	
+	#[test]
+	fn two_cycles_or_an_exhausted_budget_are_undecided() {
+		let mut parser = Parser::new();
+		assert_eq!(compare("(λx.x x) (λx.x x)", "(λy.y y) (λy.y y) a", &parser), Equivalence::Undecided);
+		
+		let mut budget = Budget::new();
+		budget.set_max_steps(Some(10));
+		parser.set_budget(&budget);
+		
+		assert_eq!(compare("(λx.x x x) (λx.x x x)", "a", &parser), Equivalence::Undecided);
+		assert_eq!(compare("(λx.x x x) (λx.x x x)", "(λx.x x x) (λx.x x x) a", &parser), Equivalence::Undecided);
+	}
+}
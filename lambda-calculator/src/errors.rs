@@ -1,36 +1,246 @@
 // -- Imports -- //
 
+use crate::reduction::Limit;
+
 use thiserror::Error;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::wasm_bindgen;
 
 // -- Typing -- //
 
 pub type Result<T> = core::result::Result<T, LambdaError>;
 
+// DOCS Character offsets into the source of an expression, where the end is excluded.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Span {
+	start: usize,
+	end: usize,
+}
+
+#[wasm_bindgen]
+impl Span {
+	#[wasm_bindgen(getter)]
+	pub fn start(&self) -> usize { self.start }
+	#[wasm_bindgen(getter)]
+	pub fn end(&self) -> usize { self.end }
+}
+
+impl Span {
+	pub(crate) fn new(start: usize, end: usize) -> Self { Self { start, end } }
+}
+
 #[derive(Debug, Error)]
 pub enum LambdaError {
 	#[error("syntax error: unexpected '{0}' character")]
-	UnexpectedCharacter(char),
+	UnexpectedCharacter(char, Span),
 	#[error("syntax error: \"{0}\" is not a valid variable name")]
-	InvalidName(String),
+	InvalidName(String, Span),
 	#[error("syntax error: incomplete lambda function declaration at the end of the expression")]
-	IncompleteFunction,
+	IncompleteFunction(Span),
 	#[error("syntax error: \"{0}\" is not a valid keyword")]
 	InvalidKeyword(String),
+	#[error("syntax error: keyword \"{0}\" has no expression")]
+	EmptyKeyword(String),
 	#[error("syntax error: closing parenthesis without matching open parenthesis")]
-	UnmatchedParenthesis,
+	UnmatchedParenthesis(Span),
+	#[error("syntax error: incomplete let expression, expected \"let name = expression in expression\"")]
+	IncompleteLet(Span),
+	#[error("syntax error: \"in\" without matching \"let\"")]
+	UnmatchedIn(Span),
+	#[error("syntax error: incomplete assertion, expected \"assert expression == expression\" or \"assert expression normalizes_to expression\"")]
+	IncompleteAssertion(Span),
 	
 	#[error("naming error: \"{0}\" is reserved as a keyword, so it cannot be assigned to a variable")]
-	ReservedName(String),
+	ReservedName(String, Span),
 	#[error("naming error: \"{0}\" has already been used as a function variable, so it cannot be used as a free term")]
-	UnavailableName(String),
-	#[error("naming error: \"{0}\" cannot be assigned multiple times in the same function")]
-	ActiveName(String),
+	UnavailableName(String, Span),
+	#[error("naming error: keyword \"{0}\" is defined more than once in the same group")]
+	DuplicateKeyword(String),
+	#[error("naming error: keyword \"{0}\" cannot use \"{1}\", because \"{1}\" already uses \"{0}\"")]
+	CyclicKeyword(String, String, Span),
+	#[error("naming error: keyword \"{1}\" uses \"{0}\", which is no longer defined")]
+	MissingKeyword(String, String, Span),
 	
-	#[error("input error: {0} is greater than maximum integer limit (255)")]
-	NumberTooLarge(usize),
+	#[error("input error: {0} is too large to be stored as a number")]
+	NumberTooLarge(String, Span),
+	
+	#[error("arithmetic error: \"{0}\" is outside of the native integer range")]
+	IntegerOverflow(String),
+	#[error("arithmetic error: \"{0}\" divides by zero")]
+	DivisionByZero(String),
+	
+	#[error("budget error: reached the {0}, partially reduced to \"{1}\"")]
+	LimitReached(Limit, String),
+	#[error("budget error: reached the depth limit of {} nested functions", crate::MAX_OBJECT_DEPTH)]
+	ExpressionSizeLimit,
+	#[error("assertion error: {0}")]
+	AssertionFailed(String, Span),
+	
+	#[error("divergence error: the strategy loops without reaching a normal form (cycle of length {0} detected)")]
+	Divergent(usize),
 	
 	#[error("internal error: an unforeseen error has occurred")]
 	InternalFailure,
-	#[error("internal error: expression reached the maximum size limit")]
-	ExpressionSizeLimit,
+}
+
+// DOCS The category of an error, which is also the first word of its message.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+	Syntax,
+	Naming,
+	Input,
+	Arithmetic,
+	Budget,
+	Assertion,
+	Divergence,
+	Internal,
+}
+
+impl LambdaError {
+	pub fn kind(&self) -> ErrorKind {
+		match self {
+			Self::UnexpectedCharacter(..) |
+			Self::InvalidName(..) |
+			Self::IncompleteFunction(..) |
+			Self::InvalidKeyword(..) |
+			Self::EmptyKeyword(..) |
+			Self::UnmatchedParenthesis(..) |
+			Self::IncompleteLet(..) |
+			Self::UnmatchedIn(..) |
+			Self::IncompleteAssertion(..) =>
+				ErrorKind::Syntax,
+			
+			Self::ReservedName(..) |
+			Self::UnavailableName(..) |
+			Self::DuplicateKeyword(..) |
+			Self::CyclicKeyword(..) |
+			Self::MissingKeyword(..) =>
+				ErrorKind::Naming,
+			
+			Self::NumberTooLarge(..) =>
+				ErrorKind::Input,
+			
+			Self::IntegerOverflow(..) |
+			Self::DivisionByZero(..) =>
+				ErrorKind::Arithmetic,
+			
+			Self::LimitReached(..) |
+			Self::ExpressionSizeLimit =>
+				ErrorKind::Budget,
+			
+			Self::AssertionFailed(..) =>
+				ErrorKind::Assertion,
+			
+			Self::Divergent(..) =>
+				ErrorKind::Divergence,
+			
+			Self::InternalFailure =>
+				ErrorKind::Internal,
+		}
+	}
+	
+	// DOCS Only errors found while reading an expression have a span.
+	pub fn span(&self) -> Option<Span> {
+		match self {
+			Self::UnexpectedCharacter(_, span) |
+			Self::InvalidName(_, span) |
+			Self::IncompleteFunction(span) |
+			Self::UnmatchedParenthesis(span) |
+			Self::IncompleteLet(span) |
+			Self::UnmatchedIn(span) |
+			Self::IncompleteAssertion(span) |
+			Self::ReservedName(_, span) |
+			Self::UnavailableName(_, span) |
+			Self::CyclicKeyword(_, _, span) |
+			Self::MissingKeyword(_, _, span) |
+			Self::NumberTooLarge(_, span) |
+			Self::AssertionFailed(_, span) =>
+				Some(*span),
+			
+			_ => None,
+		}
+	}
+	
+	// DOCS Formats the error followed by the line of the source that it's on, with carets
+	// 		under the span of the error.
+	pub fn underline(&self, source: &str) -> String {
+		match self.span() {
+			Some(span) => underline(&self.to_string(), span, source),
+			None => self.to_string(),
+		}
+	}
+}
+
+pub(crate) fn underline(message: &str, span: Span, source: &str) -> String {
+	let chars = source.chars().collect::<Vec<char>>();
+	let start = span.start.min(chars.len());
+	
+	let line_start = chars[..start].iter().rposition(|c| *c == '\n').map_or(0, |i| i + 1);
+	let line_end = chars[start..].iter().position(|c| *c == '\n').map_or(chars.len(), |i| start + i);
+	let width = span.end.clamp(start, line_end).saturating_sub(start).max(1);
+	
+	format!(
+		"{message}\n{}\n{}{}",
+		chars[line_start..line_end].iter().collect::<String>(),
+		" ".repeat(start - line_start),
+		"^".repeat(width),
+	)
+}
+
+// DOCS An error in a form that JavaScript can inspect without parsing the message.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorReport {
+	pub kind: ErrorKind,
+	pub message: String,
+	pub span: Option<Span>,
+}
+
+impl From<LambdaError> for ErrorReport {
+	fn from(e: LambdaError) -> Self { Self { kind: e.kind(), message: e.to_string(), span: e.span() } }
+}
+
+impl From<LambdaError> for JsValue {
+	fn from(e: LambdaError) -> Self { JsValue::from_str(&e.to_string()) }
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::parser::Parser;
+	use crate::term::Term;
+	
+	#[test]
+	fn reports_keep_the_kind_and_span() {
+		let mut parser = Parser::new();
+		
+		let report = ErrorReport::from(Term::parse("a (b $c)", &parser).unwrap_err());
+		assert_eq!((report.kind, report.span), (ErrorKind::Syntax, Some(Span { start: 5, end: 7 })));
+		
+		let report = parser.define_keyword("k".to_string(), "λlet.a".to_string()).unwrap_err();
+		assert_eq!((report.kind, report.span), (ErrorKind::Syntax, Some(Span { start: 1, end: 4 })));
+		assert_eq!(report.message, "syntax error: \"let\" is not a valid variable name");
+		
+		let report = parser.define_keyword("let".to_string(), "a".to_string()).unwrap_err();
+		assert_eq!((report.kind, report.span), (ErrorKind::Syntax, None));
+	}
+	
+	#[test]
+	fn spans_count_characters_across_lines() {
+		// `λ` is two bytes but one character, so the offsets below are only right in characters.
+		let text = "id = λx.x\nk = λx.λy.x\n\nid (k λz.$a)\n";
+		let results = Parser::new().load_program(text.to_string());
+		
+		let failed = &results[2];
+		assert_eq!((failed.line, failed.result.span), (4, Some(Span { start: 32, end: 34 })));
+		assert_eq!(text.chars().skip(32).take(2).collect::<String>(), "$a");
+		assert_eq!(failed.result.underline(text), "syntax error: \"$a\" is not a valid variable name\nid (k λz.$a)\n         ^^");
+		
+		let error = LambdaError::UnmatchedParenthesis(Span { start: 12, end: 13 });
+		assert_eq!(error.underline("a\nλb.b\n(c d))\ne"), "syntax error: closing parenthesis without matching open parenthesis\n(c d))\n     ^");
+	}
 }
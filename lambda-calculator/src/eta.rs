@@ -0,0 +1,175 @@
+// -- Imports -- //
+
+use crate::errors::{LambdaError, Result};
+use crate::naming::{NameID, NameSpace};
+use crate::objects::{Expr, Object};
+use crate::reduction::Strategy;
+
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+
+// -- Eta Reduction -- //
+
+impl Object {
+	// DOCS Finds the leftmost outermost function of the form `λx.f x` where `x` doesn't occur in
+	// 		`f`. Weak strategies never reduce functions, so they never find eta redexes.
+	pub fn find_eta_redex(&self, strategy: Strategy, path: &mut Vec<usize>) -> bool {
+		let Self::Func { vars: _, inner } = self else { return false };
+		
+		if strategy.is_weak() { return false }
+		if self.is_eta_redex() { return true }
+		
+		for (index, o) in inner.iter().enumerate() {
+			path.push(index);
+			if o.find_eta_redex(strategy, path) { return true }
+			path.pop();
+		}
+		
+		false
+	}
+	
+	// DOCS The last variable of a function is the innermost one, so it's the only variable that
+	// 		can be dropped. Dropping it leaves the queue positions of the other variables intact.
+	// 		`λx.2 f x` is really `λx.f (f x)`, so it isn't a redex.
+	fn is_eta_redex(&self) -> bool {
+		let Self::Func { vars, inner } = self else { return false };
+		
+		let Some(last_var) = vars.len().checked_sub(1) else { return false };
+		if inner.len() < 2 || (inner.len() == 3 && Self::is_iteration(inner)) { return false }
+		
+		let Some(Self::Var { depth: 0, queue_position }) = inner.back() else { return false };
+		if *queue_position != last_var { return false }
+		
+		inner.iter().take(inner.len() - 1).all(|o| !o.mentions(0, last_var))
+	}
+	
+	pub(crate) fn mentions(&self, recursion_depth: usize, var: usize) -> bool {
+		match self {
+			Self::Term { name: _ } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } => false,
+			Self::Var { depth, queue_position } => *depth == recursion_depth && *queue_position == var,
+			Self::Func { vars: _, inner } => inner.iter().any(|o| o.mentions(recursion_depth + 1, var)),
+		}
+	}
+	
+	pub fn drop_eta(&mut self) -> Result<()> {
+		if !self.is_eta_redex() { return Err(LambdaError::InternalFailure) }
+		let Self::Func { vars, inner } = self else { return Err(LambdaError::InternalFailure) };
+		
+		vars.pop_back();
+		inner.pop_back();
+		
+		Ok(())
+	}
+}
+
+// -- Eta Expansion -- //
+
+// DOCS Untyped terms have no eta-long form of their own, so a variable is expanded to take
+// 		as many arguments as it is given anywhere else in the expression. `λx.g f (f x)`
+// 		expands to `λx.g (λa.f a) (f x)`, while normal forms like `λf.λx.f (f x)` are kept.
+impl Expr {
+	pub fn expand_eta(&mut self) {
+		let mut arities = Arities::default();
+		arities.measure(&self.object, &mut Vec::new(), &mut 0, &self.name_space);
+		
+		let fresh = self.name_space.add_fresh_name();
+		arities.expand(&mut self.object, &mut Vec::new(), &mut 0, fresh, &self.name_space);
+	}
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+enum Key {
+	Bound(usize),
+	Free(NameID),
+}
+
+// DOCS Function variables are keyed by the order in which their functions are visited, so
+// 		`measure` and `expand` have to walk the expression in the same order.
+#[derive(Clone, Debug, Default)]
+struct Arities {
+	max: HashMap<Key, usize>,
+}
+
+impl Arities {
+	fn key(&self, object: &Object, scopes: &[Vec<usize>], name_space: &NameSpace) -> Option<Key> {
+		match object {
+			Object::Term { name } =>
+				Some(Key::Free(name_space.get_absolute_name_index(*name))),
+			Object::Var { depth, queue_position } =>
+				Some(Key::Bound(scopes[scopes.len() - 1 - depth][*queue_position])),
+			Object::Func { .. } | Object::Num { value: _ } | Object::Int { value: _ } | Object::Prim { op: _ } =>
+				None,
+		}
+	}
+	
+	fn measure(&mut self, object: &Object, scopes: &mut Vec<Vec<usize>>, next_id: &mut usize, name_space: &NameSpace) {
+		let Object::Func { vars, inner } = object else { return };
+		
+		scopes.push((*next_id..*next_id + vars.len()).collect());
+		*next_id += vars.len();
+		
+		let is_iteration = Object::is_iteration(inner);
+		
+		for (index, o) in inner.iter().enumerate() {
+			if let Some(key) = self.key(o, scopes, name_space) {
+				let arity = match index {
+					0 => inner.len() - 1,
+					1 if is_iteration => 1,
+					_ => 0,
+				};
+				let max = self.max.entry(key).or_insert(0);
+				*max = arity.max(*max);
+			} else {
+				self.measure(o, scopes, next_id, name_space);
+			}
+		}
+		
+		scopes.pop();
+	}
+	
+	fn expand(&self, object: &mut Object, scopes: &mut Vec<Vec<usize>>, next_id: &mut usize, fresh: NameID, name_space: &NameSpace) {
+		let Object::Func { vars, inner } = object else { return };
+		
+		let var_count = vars.len();
+		scopes.push((*next_id..*next_id + var_count).collect());
+		*next_id += var_count;
+		
+		// Iterations of functions that take more than one argument are written out, one application at a time.
+		if let Some((_, f, _)) = Object::as_iteration(inner)
+			&& self.key(f, scopes, name_space).is_some_and(|key| self.max[&key] > 1) {
+			Object::unfold_iteration(inner);
+		}
+		
+		let is_iteration = Object::is_iteration(inner);
+		
+		for index in 0..inner.len() {
+			let Some(key) = self.key(&inner[index], scopes, name_space) else {
+				self.expand(&mut inner[index], scopes, next_id, fresh, name_space);
+				continue
+			};
+			
+			let arity = self.max[&key];
+			
+			// Objects at the front of a closure are expanded by extending the closure itself.
+			if index == 0 {
+				let missing = arity.saturating_sub(inner.len() - 1);
+				for position in var_count..var_count + missing {
+					vars.push_back((fresh, Cell::new(0)));
+					inner.push_back(Object::Var { depth: 0, queue_position: position });
+				}
+			} else if arity > 0 && !(index == 1 && is_iteration) {
+				let lifted = match &inner[index] {
+					Object::Var { depth, queue_position } => Object::Var { depth: depth + 1, queue_position: *queue_position },
+					o => o.clone(),
+				};
+				
+				let mut wrapped = VecDeque::from([lifted]);
+				wrapped.extend((0..arity).map(|queue_position| Object::Var { depth: 0, queue_position }));
+				
+				inner[index] = Object::Func { vars: (0..arity).map(|_| (fresh, Cell::new(0))).collect(), inner: wrapped };
+			}
+		}
+		
+		scopes.pop();
+	}
+}
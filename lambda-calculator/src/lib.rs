@@ -2,39 +2,94 @@
 
 mod parser;
 mod builder;
+mod debruijn;
+mod delta;
+mod equivalence;
+mod eta;
 mod naming;
 mod objects;
 mod printer;
 mod errors;
+mod prelude;
+mod program;
+mod reduction;
+mod term;
 
 // -- Imports -- //
 
-use thiserror::__private::AsDynError;
 use wasm_bindgen::prelude::*;
 
 // -- Exports -- //
 
+pub use crate::delta::Primitive;
+pub use crate::equivalence::{equivalent, Equivalence};
+pub use crate::errors::{LambdaError, Span};
 pub use crate::parser::Parser;
+pub use crate::printer::Style;
+pub use crate::program::{LineResult, TestFailure, TestReport};
+pub use crate::errors::{ErrorKind, ErrorReport};
+pub use crate::reduction::{Arithmetic, Budget, EvalResult, Eta, Limit, Status, Strategy, Trace, TraceStep};
+pub use crate::term::Term;
 
 #[wasm_bindgen]
 pub fn simplify(expression: String, parser: &Parser) -> String {
-	if expression.is_empty() { return expression }
+	simplify_with(expression, parser, Strategy::default())
+}
+
+#[wasm_bindgen]
+pub fn simplify_with(expression: String, parser: &Parser, strategy: Strategy) -> String {
+	simplify_within(expression, parser, strategy, &parser.budget())
+}
+
+#[wasm_bindgen]
+pub fn simplify_within(expression: String, parser: &Parser, strategy: Strategy, budget: &Budget) -> String {
+	evaluate_within(expression, parser, strategy, budget).output
+}
+
+#[wasm_bindgen]
+pub fn evaluate(expression: String, parser: &Parser, strategy: Strategy) -> EvalResult {
+	evaluate_within(expression, parser, strategy, &parser.budget())
+}
+
+#[wasm_bindgen]
+pub fn evaluate_within(expression: String, parser: &Parser, strategy: Strategy, budget: &Budget) -> EvalResult {
+	if expression.is_empty() { return EvalResult::new(Ok(expression), 0, 0) }
+	
+	let mut meter = budget.start();
+	let mut size = 0;
+	
+	let result = parser
+		.tokenize(expression)
+		.and_then(builder::build)
+		.and_then(|mut expr| {
+			let reduced = expr.reduce_metered(strategy, parser.eta(), &mut meter);
+			size = expr.object.size();
+			
+			reduced.map(|_| printer::format_with(&expr, &parser.style(), parser.keyword_index()))
+		});
 	
-	solve(expression, parser).unwrap_or_else(|e| format!("{}", e.as_dyn_error()))
+	EvalResult::new(result, meter.steps(), size)
 }
 
-fn solve(s: String, p: &Parser) -> errors::Result<String> {
-	let tokens = p.tokenize(s, None)?;
-	let mut expr = builder::build(tokens)?;
-	expr.reduce()?;
-	Ok(printer::format(&expr))
+#[wasm_bindgen]
+pub fn trace(expression: String, parser: &Parser, strategy: Strategy) -> Vec<TraceStep> {
+	if expression.is_empty() { return Vec::new() }
+	
+	match Trace::new(expression, parser, strategy, &parser.budget()) {
+		Ok(t) => t.map(|step| step.unwrap_or_else(|e| TraceStep::failed(&e))).collect(),
+		Err(e) => vec![TraceStep::failed(&e)],
+	}
 }
 
 // -- Configuration -- //
 
-const MAX_NUMBER_INPUT: usize = 255;
 const MAX_OBJECT_DEPTH: usize = 512;
-const MAX_STRING_LENGTH: usize = 255;
+
+const DEFAULT_MAX_STEPS: usize = 100_000;
+const DEFAULT_MAX_SIZE: usize = 100_000;
+const DEFAULT_MAX_TIME: f64 = 5_000.0;
+
+const MAX_INDEX_STEPS: usize = 250;
 
 // -- Debug -- //
 
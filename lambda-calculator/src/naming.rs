@@ -42,4 +42,30 @@ impl NameSpace {
 	pub fn get_absolute_name_index(&self, name_id: NameID) -> NameID {
 		*self.renames.get(&name_id).unwrap_or(&name_id)
 	}
+	
+	// DOCS Finds or adds a function variable name, falling back to a fresh name when the
+	// 		name is already a free term (free terms are the only names with no copies).
+	pub fn get_or_add_var_name(&mut self, name: &str) -> NameID {
+		if let Some(name_id) = self.names.iter().position(|n| n == name) {
+			return if self.copies[name_id] != 0 { name_id } else { self.add_fresh_name() }
+		}
+		
+		self.names.push(name.to_string());
+		self.copies.push(1);
+		
+		self.names.len() - 1
+	}
+	
+	// DOCS Adds a single letter name that isn't used anywhere else in the expression.
+	pub fn add_fresh_name(&mut self) -> NameID {
+		let name = ('a'..='z')
+			.map(String::from)
+			.find(|n| !self.names.contains(n))
+			.unwrap_or_else(|| format!("v{}", self.names.len()));
+		
+		self.names.push(name);
+		self.copies.push(1);
+		
+		self.names.len() - 1
+	}
 }
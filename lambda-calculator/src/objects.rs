@@ -1,12 +1,18 @@
 // -- Imports -- //
 
 use crate::MAX_OBJECT_DEPTH;
+use crate::delta::Primitive;
 use crate::errors::{LambdaError, Result};
 use crate::naming::{NameID, CopyID, NameSpace};
+use crate::reduction::{Budget, Eta, Meter, Strategy};
 
 use std::collections::VecDeque;
 use std::cell::Cell;
 
+// -- Typing -- //
+
+type Vars = VecDeque<(NameID, Cell<CopyID>)>;
+
 // -- Expr -- //
 
 #[derive(Clone, Debug)]
@@ -16,9 +22,56 @@ pub(crate) struct Expr {
 }
 
 impl Expr {
-	pub fn new(object: Object, name_space: NameSpace) -> Self { Self { object, name_space } }
+	pub fn new(mut object: Object, name_space: NameSpace) -> Self {
+		object.tidy();
+		Self { object, name_space }
+	}
+	
+	pub fn reduce(&mut self, strategy: Strategy, eta: Eta, budget: &Budget) -> Result<()> {
+		self.reduce_metered(strategy, eta, &mut budget.start())
+	}
+	
+	pub fn reduce_metered(&mut self, strategy: Strategy, eta: Eta, meter: &mut Meter) -> Result<()> {
+		while let Some(redex) = self.find_redex(strategy, eta) {
+			meter.step(self)?;
+			self.contract(&redex)?;
+		}
+		
+		if eta == Eta::Expand { self.expand_eta() }
+		
+		Ok(())
+	}
 	
-	pub fn reduce(&mut self) -> Result<()> { self.object.reduce(&mut self.name_space) }
+	// DOCS Eta redexes are only looked for once there are no beta redexes left.
+	pub fn find_redex(&self, strategy: Strategy, eta: Eta) -> Option<Redex> {
+		let mut path = Vec::new();
+		
+		if self.object.find_redex(strategy, &mut path) {
+			if self.object.at(&path).is_some_and(|o| o.is_delta_redex()) { Some(Redex::Delta(path)) }
+			else { Some(Redex::Beta(path)) }
+		} else if eta == Eta::Reduce && self.object.find_eta_redex(strategy, &mut path) { Some(Redex::Eta(path)) }
+		else { None }
+	}
+	
+	pub fn contract(&mut self, redex: &Redex) -> Result<()> {
+		match redex {
+			Redex::Beta(path) => self.object.contract(path, &mut |o| o.apply_head(&mut self.name_space)),
+			Redex::Eta(path) => self.object.contract(path, &mut |o| o.drop_eta()),
+			Redex::Delta(path) => self.object.contract(path, &mut |o| o.apply_delta(&mut self.name_space)),
+		}
+	}
+}
+
+// -- Redexes -- //
+
+// DOCS Beta paths lead to the closure whose first object is applied to its second object,
+// 		eta paths lead to the function that can be dropped, and delta paths lead to the
+// 		closure whose first object is a primitive applied to all of its arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Redex {
+	Beta(Vec<usize>),
+	Eta(Vec<usize>),
+	Delta(Vec<usize>),
 }
 
 // -- Objects -- //
@@ -28,77 +81,116 @@ pub(crate) enum Object {
 	Func { vars: VecDeque<(NameID, Cell<CopyID>)>, inner: VecDeque<Object> },
 	Var { depth: usize, queue_position: usize },
 	Term { name: NameID },
+	Num { value: usize },
+	Int { value: i64 },
+	Prim { op: Primitive },
 }
 
 impl Object {
-	pub fn reduce(&mut self, name_space: &mut NameSpace) -> Result<()> {
-		// DOCS Return early if self isn't a Func.
-		let Self::Func {
-			vars: parent_vars,
-			inner: objects
-		} = self else { return Ok(()) };
+	// DOCS Finds the next redex picked by the strategy. The path lists the `inner` indices
+	// 		leading to the closure whose first object is applied to its second object.
+	pub fn find_redex(&self, strategy: Strategy, path: &mut Vec<usize>) -> bool {
+		let Self::Func { vars, inner } = self else { return false };
 		
-		// DOCS Return early if self is empty.
-		let Some(hold) = objects.pop_front() else { return Ok(()) };
+		// Weak strategies never reduce inside of a lambda function.
+		if !vars.is_empty() && strategy.is_weak() { return false }
 		
-		let (hold, cycles) = hold.reduce_in_closure(objects, name_space)?;
-		objects.push_back(hold);
+		let Some(head) = inner.front() else { return false };
 		
-		// Cycles the front object to the front.
-		for _ in 0..cycles {
-			let mut temp = objects.pop_front().unwrap();
-			temp.reduce(name_space)?;
-			objects.push_back(temp);
+		if self.is_delta_redex() { return true }
+		if self.strict_arguments().any(|i| Self::find_redex_at(inner, i, strategy, path)) { return true }
+		
+		let is_redex = inner.len() > 1 && head.is_function() && !Self::is_iteration(inner);
+		
+		match strategy {
+			Strategy::Normal => {
+				if is_redex { return true }
+				(0..inner.len()).any(|i| Self::find_redex_at(inner, i, strategy, path))
+			}
+			
+			Strategy::Applicative => {
+				if Self::find_redex_at(inner, 0, strategy, path) { return true }
+				if is_redex {
+					Self::find_redex_at(inner, 1, strategy, path);
+					return true
+				}
+				
+				(1..inner.len()).any(|i| Self::find_redex_at(inner, i, strategy, path))
+			}
+			
+			Strategy::CallByName =>
+				is_redex,
+			
+			Strategy::CallByValue => {
+				if is_redex {
+					Self::find_redex_at(inner, 1, strategy, path);
+					return true
+				}
+				
+				(1..inner.len()).any(|i| Self::find_redex_at(inner, i, strategy, path))
+			}
 		}
+	}
+	
+	fn find_redex_at(inner: &VecDeque<Object>, index: usize, strategy: Strategy, path: &mut Vec<usize>) -> bool {
+		path.push(index);
+		if inner[index].find_redex(strategy, path) { return true }
+		path.pop();
 		
-		// Lowers single item closures. `(a) => a`
-		if parent_vars.is_empty() && objects.len() == 1 {
-			let mut pop = objects.pop_front().unwrap();
-			pop.lower(0);
-			*self = pop;
+		false
+	}
+	
+	pub(crate) fn at(&self, path: &[usize]) -> Option<&Self> {
+		let Some((index, path)) = path.split_first() else { return Some(self) };
+		let Self::Func { vars: _, inner } = self else { return None };
+		
+		inner.get(*index)?.at(path)
+	}
+	
+	// DOCS Rewrites the object at the end of the path, then tidies every closure along the path.
+	fn contract(&mut self, path: &[usize], rewrite: &mut impl FnMut(&mut Self) -> Result<()>) -> Result<()> {
+		if let Some((index, path)) = path.split_first() {
+			let Self::Func { vars: _, inner } = self else { return Err(LambdaError::InternalFailure) };
+			let Some(object) = inner.get_mut(*index) else { return Err(LambdaError::InternalFailure) };
+			object.contract(path, rewrite)?;
+		} else {
+			rewrite(self)?;
 		}
 		
+		self.tidy_closure();
+		
 		Ok(())
 	}
 	
-	fn reduce_in_closure(mut self, objects: &mut VecDeque<Object>, name_space: &mut NameSpace) -> Result<(Self, usize)> {
-		let mut remaining_objects = objects.len();
+	// DOCS Applies the first object of the closure to its second object.
+	fn apply_head(&mut self, name_space: &mut NameSpace) -> Result<()> {
+		let Self::Func { vars: _, inner } = self else { return Err(LambdaError::InternalFailure) };
 		
-		'reduction: while remaining_objects != 0 {
-			self.reduce(name_space)?;
-			self.expand_closures(objects, &mut remaining_objects, name_space);
-			
-			let Self::Func {
-				mut vars,
-				mut inner
-			} = self else { break 'reduction };
-			
-			// This covers cases with blank closures.
-			if vars.is_empty() {
-				self = Self::Func { vars, inner };
-				break 'reduction;
-			}
-			
-			vars.pop_front();
-			remaining_objects -= 1;
-			let beta = objects.pop_front().unwrap();
-			
-			for o in inner.iter_mut() { o.apply_beta(name_space, &beta, 0)? }
-			
-			// TODO name_space.drop_beta(beta);
-			
-			self = Self::Func { vars, inner };
-		}
+		let (mut vars, mut body) = match inner.pop_front() {
+			Some(Self::Func { vars, inner }) => match Self::as_counted_numeral(&vars, &inner) {
+				Some((value, vars)) => (vars, Self::unfold_numeral(value, name_space).1),
+				None => (vars, inner),
+			},
+			Some(Self::Num { value }) => Self::unfold_numeral(value, name_space),
+			_ => return Err(LambdaError::InternalFailure),
+		};
+		let Some(beta) = inner.pop_front() else { return Err(LambdaError::InternalFailure) };
+		
+		vars.pop_front();
+		for o in body.iter_mut() { o.apply_beta(name_space, &beta, 0)? }
 		
-		self.reduce(name_space)?;
-		self.expand_closures(objects, &mut remaining_objects, name_space);
+		// TODO name_space.drop_beta(beta);
 		
-		Ok((self, remaining_objects))
+		let mut hold = Self::Func { vars, inner: body };
+		hold.tidy();
+		inner.push_front(hold);
+		
+		Ok(())
 	}
 	
 	fn apply_beta(&mut self, name_space: &mut NameSpace, beta: &Self, recursion_depth: usize) -> Result<()> {
 		match self {
-			Self::Term { name: _ } => (),
+			Self::Term { name: _ } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } => (),
 			
 			Self::Var { depth, queue_position } => {
 				if *depth == recursion_depth {
@@ -118,12 +210,197 @@ impl Object {
 		
 		Ok(())
 	}
+	
+	// DOCS Numerals are only unfolded one application at a time. `n f` becomes
+	// 		`λx.f ((n - 1) f x)`, whose applications of `f` are counted again by `tidy_closure`
+	// 		as soon as `f` is a variable or a free term.
+	fn unfold_numeral(value: usize, name_space: &mut NameSpace) -> (VecDeque<(NameID, Cell<CopyID>)>, VecDeque<Object>) {
+		let f = Self::Var { depth: 0, queue_position: 0 };
+		let x = Self::Var { depth: 0, queue_position: 1 };
+		
+		let vars = [name_space.get_or_add_var_name("f"), name_space.get_or_add_var_name("x")]
+			.into_iter()
+			.map(|name_id| (name_id, Cell::new(0)))
+			.collect();
+		
+		let inner = if value == 0 { VecDeque::from([x]) }
+		else {
+			let rest = Self::Func {
+				vars: VecDeque::new(),
+				inner: VecDeque::from([Self::Num { value: value - 1 }, f.cloned_raw(1), x.cloned_raw(1)]),
+			};
+			
+			VecDeque::from([f, rest])
+		};
+		
+		(vars, inner)
+	}
+	
+	pub(crate) fn is_function(&self) -> bool {
+		match self {
+			Self::Func { vars, inner: _ } => !vars.is_empty(),
+			Self::Num { value: _ } => true,
+			_ => false,
+		}
+	}
+}
+
+// -- Object Structure -- //
+
+impl Object {
+	pub fn depth(&self) -> usize {
+		match self {
+			Self::Term { name: _ } | Self::Var { .. } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } => 0,
+			Self::Func { vars: _, inner } => 1 + inner.iter().map(|o| o.depth()).max().unwrap_or(0),
+		}
+	}
+	
+	// DOCS Counts every term, variable, and function variable in the object.
+	pub fn size(&self) -> usize {
+		match self {
+			Self::Term { name: _ } | Self::Var { .. } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } => 1,
+			Self::Func { vars, inner } => vars.len() + inner.iter().map(|o| o.size()).sum::<usize>(),
+		}
+	}
+	
+	// DOCS Flattens closures without variables into their parents, so that the first object
+	// 		of every closure is either a function ready to be applied or a term that can't be.
+	pub fn tidy(&mut self) {
+		let Self::Func { vars: _, inner } = self else { return };
+		
+		inner.iter_mut().for_each(|o| o.tidy());
+		self.tidy_closure();
+	}
+	
+	pub(crate) fn tidy_closure(&mut self) {
+		let Self::Func { vars, inner } = self else { return };
+		
+		if let Some(mut hold) = inner.pop_front() {
+			hold.expand_closures(inner);
+			inner.push_front(hold);
+		}
+		
+		// Lowers single item closures. `(a) => a`
+		if vars.is_empty() && inner.len() == 1 {
+			let mut pop = inner.pop_front().unwrap();
+			pop.lower(0);
+			*self = pop;
+		} else {
+			Self::fold_iteration(inner);
+		}
+	}
+}
+
+// -- Iterations -- //
+
+// DOCS A numeral applied to a variable or a free term, like `2 f x`, can't be reduced any further
+// 		than `f (f x)`, so it's kept as it is and only written out when printed. Applications
+// 		like `f (f x)` are counted into that form as well, which keeps arithmetic on numerals
+// 		from nesting the expression as deep as the numbers are large.
+impl Object {
+	fn is_atom(&self) -> bool { matches!(self, Self::Var { .. } | Self::Term { name: _ }) }
+	
+	pub(crate) fn is_iteration(inner: &VecDeque<Object>) -> bool { Self::as_iteration(inner).is_some() }
+	
+	// DOCS Splits `n f x ...` into the number of times `f` is applied, `f`, and `x`.
+	pub(crate) fn as_iteration(inner: &VecDeque<Object>) -> Option<(usize, &Object, &Object)> {
+		let (Some(Self::Num { value }), Some(f), Some(x)) = (inner.front(), inner.get(1), inner.get(2)) else { return None };
+		
+		if *value > 0 && f.is_atom() { Some((*value, f, x)) }
+		else { None }
+	}
+	
+	// DOCS Like `as_iteration`, but also counts `f x` as a single application of `f`.
+	fn as_application_of_atom(inner: &VecDeque<Object>) -> Option<(usize, &Object, &Object)> {
+		match inner.len() {
+			2 if inner[0].is_atom() => Some((1, &inner[0], &inner[1])),
+			_ => Self::as_iteration(inner),
+		}
+	}
+	
+	// DOCS Rewrites `f (n f x)` as `(n + 1) f x` and `1 f x` as `f x`. The inner `f` is inside of
+	// 		one more closure than the outer `f`, so its variables are one level deeper.
+	fn fold_iteration(inner: &mut VecDeque<Object>) {
+		let Some((count, f, x)) = Self::as_application_of_atom(inner) else { return };
+		
+		let folded = match x {
+			Self::Func { vars, inner: next } if vars.is_empty() && next.len() <= 3 => {
+				let Some((next_count, next_f, next_x)) = Self::as_application_of_atom(next) else { return };
+				
+				let is_same = match (f, next_f) {
+					(Self::Var { depth, queue_position }, Self::Var { depth: next_depth, queue_position: next_position }) =>
+						*next_depth == depth + 1 && next_position == queue_position,
+					(Self::Term { name }, Self::Term { name: next_name }) => name == next_name,
+					_ => false,
+				};
+				
+				if !is_same { return }
+				Some((count + next_count, next_x.clone().lowered()))
+			}
+			
+			_ => None,
+		};
+		
+		let has_count = inner.len() > 2 || count > 1;
+		
+		match folded {
+			Some((count, x)) => {
+				if has_count { inner.pop_front(); }
+				inner[1] = x;
+				inner.push_front(Self::Num { value: count });
+			}
+			
+			None if count == 1 && inner.len() > 2 => { inner.pop_front(); }
+			None => (),
+		}
+	}
+	
+	// DOCS Matches `λf.λx.n f x`, which is applied like the numeral `n` so that applying it
+	// 		doesn't just give back `n f x` with `f` replaced. `λf.(λx.n f x)` is the same
+	// 		numeral written as two separate functions, so it's matched as well, and the
+	// 		variables of both functions are returned.
+	fn as_counted_numeral(vars: &Vars, inner: &VecDeque<Object>) -> Option<(usize, Vars)> {
+		match (vars.len(), inner.len(), inner.front()) {
+			(2, 3, _) => {
+				let (value, Self::Var { depth: 0, queue_position: 0 }, Self::Var { depth: 0, queue_position: 1 }) = Self::as_iteration(inner)?
+				else { return None };
+				
+				Some((value, vars.clone()))
+			}
+			
+			(1, 1, Some(Self::Func { vars: next_vars, inner: next })) if next_vars.len() == 1 && next.len() == 3 => {
+				let (value, Self::Var { depth: 1, queue_position: 0 }, Self::Var { depth: 0, queue_position: 0 }) = Self::as_iteration(next)?
+				else { return None };
+				
+				Some((value, vars.iter().chain(next_vars).cloned().collect()))
+			}
+			
+			_ => None,
+		}
+	}
+	
+	// DOCS Undoes one application of `fold_iteration`, turning `n f x ...` into `f ((n - 1) f x) ...`.
+	pub(crate) fn unfold_iteration(inner: &mut VecDeque<Object>) {
+		let Some((count, f, x)) = Self::as_iteration(inner) else { return };
+		
+		let x = match count {
+			1 => x.clone(),
+			2 => Self::Func { vars: VecDeque::new(), inner: VecDeque::from([f.cloned_raw(1), x.clone().raised()]) },
+			_ => Self::Func {
+				vars: VecDeque::new(),
+				inner: VecDeque::from([Self::Num { value: count - 1 }, f.cloned_raw(1), x.clone().raised()]),
+			},
+		};
+		
+		inner.pop_front();
+		inner[1] = x;
+	}
 }
 
 // -- Object Depth Mutations -- //
 
 impl Object {
-	fn cloned(&self, name_space: &mut NameSpace, origin_offset: usize, recursion_depth: usize) -> Result<Self> {
+	pub(crate) fn cloned(&self, name_space: &mut NameSpace, origin_offset: usize, recursion_depth: usize) -> Result<Self> {
 		if origin_offset + recursion_depth > MAX_OBJECT_DEPTH { return Err(LambdaError::ExpressionSizeLimit) };
 		
 		match self {
@@ -134,7 +411,7 @@ impl Object {
 			// 		function. "origin_offset" is only the recursive depth within the
 			// 		function, meaning that the x in "(λx.x)" has an origin offset of 0.
 			
-			Self::Term { name: _ } =>
+			Self::Term { name: _ } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } =>
 				Ok(self.clone()),
 			
 			Self::Func { vars, inner } => {
@@ -163,10 +440,19 @@ impl Object {
 	}
 	
 	fn lowered(mut self) -> Self { self.lower(0); self }
+	fn raised(mut self) -> Self { self.raise(0); self }
+	
+	// DOCS Only used for objects that contain no functions, so that no names are copied.
+	fn cloned_raw(&self, levels: usize) -> Self {
+		match self {
+			Self::Var { depth, queue_position } => Self::Var { depth: depth + levels, queue_position: *queue_position },
+			o => o.clone(),
+		}
+	}
 	
 	fn lower(&mut self, recursion_depth: usize) {
 		match self {
-			Self::Term { name: _ } =>
+			Self::Term { name: _ } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } =>
 				(),
 			
 			Self::Var { depth, queue_position: _ } =>
@@ -177,19 +463,26 @@ impl Object {
 		}
 	}
 	
-	fn expand_closures(
-		&mut self,
-		objects: &mut VecDeque<Object>,
-		remaining_objects: &mut usize,
-		name_space: &mut NameSpace
-	) {
+	fn raise(&mut self, recursion_depth: usize) {
+		match self {
+			Self::Term { name: _ } | Self::Num { value: _ } | Self::Int { value: _ } | Self::Prim { op: _ } =>
+				(),
+			
+			Self::Var { depth, queue_position: _ } =>
+				if *depth >= recursion_depth { *depth += 1 },
+			
+			Self::Func { vars: _, inner } =>
+				inner.iter_mut().for_each(|o| o.raise(recursion_depth + 1)),
+		}
+	}
+	
+	fn expand_closures(&mut self, objects: &mut VecDeque<Object>) {
 		let Self::Func {
 			vars,
 			inner
 		} = self else { return };
 		
 		if vars.is_empty() && let Some(object) = inner.pop_front() {
-			*remaining_objects += inner.len();
 			inner
 				.drain(0..inner.len())
 				.rev()
@@ -198,7 +491,7 @@ impl Object {
 				);
 			
 			*self = object.lowered();
-			self.expand_closures(objects, remaining_objects, name_space);
+			self.expand_closures(objects);
 		}
 	}
 }
@@ -218,6 +511,15 @@ impl Display for Expr {
 			Object::Var { depth: _, queue_position: _ } =>
 				panic!(),
 			
+			Object::Num { value } =>
+				write!(f, "{value}"),
+			
+			Object::Int { value } =>
+				write!(f, "{value}"),
+			
+			Object::Prim { op } =>
+				write!(f, "{}", op.name()),
+			
 			Object::Func { vars, inner } => {
 				let mut var_list = Vec::new();
 				
@@ -253,6 +555,15 @@ impl Object {
 			Self::Var { depth, queue_position } =>
 				remove_extra(expr, var_list[var_list.len() - 1 - *depth][*queue_position].0),
 			
+			Self::Num { value } =>
+				value.to_string(),
+			
+			Self::Int { value } =>
+				value.to_string(),
+			
+			Self::Prim { op } =>
+				op.name().to_string(),
+			
 			Self::Func { vars, inner } => {
 				let s_1 = vars
 					.iter()
@@ -281,3 +592,50 @@ fn remove_extra(expr: &Expr, name: NameID) -> String {
 	use crate::parser::KEYWORD_VARIABLE_TEST;
 	KEYWORD_VARIABLE_TEST.replace(&expr.name_space[name], "").to_string()
 }
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use crate::{simplify, Eta, Parser};
+	
+	fn decoded(expression: &str) -> String {
+		let mut parser = Parser::with_prelude();
+		let mut style = parser.style();
+		style.set_decode(true);
+		parser.set_style(&style);
+		
+		simplify(expression.to_string(), &parser)
+	}
+	
+	#[test]
+	fn large_numerals() {
+		assert_eq!(decoded("add 600 0"), "600");
+		assert_eq!(decoded("add 1000 1"), "1001");
+		assert_eq!(decoded("pred 1000"), "999");
+		assert_eq!(decoded("sub 1000 999"), "1");
+		assert_eq!(decoded("is_eq 300 300"), "true");
+		assert_eq!(decoded("succ 65536"), "65537");
+		assert_eq!(decoded("is_lt 1000 65536"), "true");
+	}
+	
+	#[test]
+	fn iterations_are_written_out() {
+		let parser = Parser::new();
+		
+		assert_eq!(simplify("2 f x".to_string(), &parser), "f (f x)");
+		assert_eq!(simplify("3 f x y".to_string(), &parser), "f (f (f x)) y");
+		assert_eq!(simplify("λx.λy.x (x (x y))".to_string(), &parser), "λx.λy.x (x (x y))");
+		assert_eq!(simplify("(λx.x x) (λf.λx.f (f x))".to_string(), &parser), "λx.(λx_1.x (x (x (x x_1))))");
+		assert_eq!(simplify("2 (λx.x) y".to_string(), &parser), "y");
+	}
+	
+	#[test]
+	fn iterations_are_eta_expanded_like_applications() {
+		let mut parser = Parser::new();
+		parser.set_eta(Eta::Expand);
+		
+		assert_eq!(simplify("λx.f (f x)".to_string(), &parser), "λx.f (f x)");
+		assert_eq!(simplify("λf.λy.f (f (f y)) y y".to_string(), &parser), "λf.λy.f (λa.λa_1.f (λa_2.λa_3.f y a_2 a_3) a a_1) y y");
+	}
+}
@@ -1,13 +1,18 @@
 // -- Imports -- //
 
-use crate::MAX_NUMBER_INPUT;
-use crate::errors::{Result, LambdaError};
+use crate::builder;
+use crate::MAX_INDEX_STEPS;
+use crate::debruijn::DeBruijn;
+use crate::delta::Primitive;
+use crate::errors::{ErrorReport, Result, LambdaError, Span};
+use crate::printer::Style;
+use crate::reduction::{Arithmetic, Budget, Eta, Strategy};
 
 use regex::Regex;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use std::sync::LazyLock;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // -- Typing -- //
 
@@ -17,12 +22,35 @@ pub enum Token {
 	Close,
 	Var(String),
 	Func(String),
+	Num(usize),
+	Int(i64),
+	Prim(Primitive),
+	Keyword(String),
+	Let(String),
+	In,
 }
 
+// DOCS Maps the normal form of every keyword that has one to the keywords sharing it,
+// 		in the order that they were defined.
+pub(crate) type KeywordIndex = HashMap<DeBruijn, Vec<String>>;
+
+// DOCS Keywords are stored with references to the other keywords that they use, which are
+// 		only replaced by their definitions once an expression is tokenized. `dependencies`
+// 		lists the keywords that each keyword refers to directly, and `groups` lists the
+// 		members of the recursive group that each recursive keyword was defined in. `sources`
+// 		keeps the expression of every keyword as it was written.
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct Parser {
 	keywords: HashMap<String, Vec<Token>>,
+	dependencies: HashMap<String, HashSet<String>>,
+	groups: HashMap<String, Vec<String>>,
+	sources: HashMap<String, String>,
+	index: KeywordIndex,
+	budget: Budget,
+	eta: Eta,
+	arithmetic: Arithmetic,
+	style: Style,
 }
 
 // -- Tokenizing -- //
@@ -30,153 +58,573 @@ pub struct Parser {
 #[wasm_bindgen]
 impl Parser {
 	pub fn new() -> Self {
-		#[cfg(feature = "panic_hook")]
+		// The hook is only needed in the browser, and would replace the compiler's own hook
+		// when the `lambda!` macro creates a parser.
+		#[cfg(all(feature = "panic_hook", target_arch = "wasm32"))]
 		console_error_panic_hook::set_once();
 		
-		Self { keywords: HashMap::new() }
+		Self {
+			keywords: HashMap::new(),
+			dependencies: HashMap::new(),
+			groups: HashMap::new(),
+			sources: HashMap::new(),
+			index: HashMap::new(),
+			budget: Budget::new(),
+			eta: Eta::default(),
+			arithmetic: Arithmetic::default(),
+			style: Style::default(),
+		}
 	}
 	
 	pub fn create_keyword(&mut self, name: String, expression: String) -> String {
-		let name = name.trim().to_string();
-		let expression = expression.trim().to_string();
+		self.define_keyword(name, expression).unwrap_or_default()
+	}
+	
+	// DOCS Returns the trimmed expression like `create_keyword`, but throws an `ErrorReport`
+	// 		instead of returning an empty string when the keyword can't be created.
+	pub fn define_keyword(&mut self, name: String, expression: String) -> core::result::Result<String, ErrorReport> {
+		self.define(&name, &expression)?;
+		Ok(expression.trim().to_string())
+	}
+	
+	// DOCS Defines keywords that can all use each other, pairing each name with the
+	// 		expression at the same position.
+	pub fn define_keyword_group(&mut self, names: Vec<String>, expressions: Vec<String>) -> core::result::Result<(), ErrorReport> {
+		if names.len() != expressions.len() { return Err(LambdaError::InternalFailure.into()) }
 		
-		if name.is_empty() || expression.is_empty() { return String::new() }
-		if self.add_keyword(name, expression.clone()).is_err() { String::new() }
-		else { expression }
+		let definitions = names
+			.iter()
+			.zip(expressions.iter())
+			.map(|(name, expression)| (name.as_str(), expression.as_str()))
+			.collect::<Vec<(&str, &str)>>();
+		
+		Ok(self.define_group(&definitions)?)
+	}
+	
+	// DOCS Keywords that use the removed keyword are kept, but fail with `MissingKeyword`
+	// 		until it's defined again.
+	pub fn remove_keyword(&mut self, name: String) {
+		let name = name.trim();
+		
+		self.keywords.remove(name);
+		self.sources.remove(name);
+		self.dependencies.remove(name);
+		self.leave_group(name);
+		self.unindex_keyword(name);
+		self.revalidate(name);
+	}
+	
+	// DOCS Lists every keyword that uses the keyword, either directly or through other
+	// 		keywords, in alphabetical order.
+	pub fn dependents_of(&self, name: String) -> Vec<String> {
+		let mut found = HashSet::<&str>::new();
+		let mut pending = vec![name.trim()];
+		
+		while let Some(name) = pending.pop() {
+			for (user, dependencies) in self.dependencies.iter() {
+				if dependencies.contains(name) && found.insert(user) { pending.push(user) }
+			}
+		}
+		
+		found.remove(name.trim());
+		
+		let mut out = found.into_iter().map(String::from).collect::<Vec<String>>();
+		out.sort();
+		
+		out
+	}
+	
+	// DOCS Lists every keyword in alphabetical order.
+	pub fn keyword_names(&self) -> Vec<String> {
+		let mut names = self.keywords.keys().cloned().collect::<Vec<String>>();
+		names.sort();
+		
+		names
+	}
+	
+	pub fn keyword_expression(&self, name: String) -> Option<String> {
+		self.sources.get(name.trim()).cloned()
 	}
 	
-	pub fn remove_keyword(&mut self, name: String) { self.keywords.remove(name.trim()); }
+	pub fn budget(&self) -> Budget { self.budget }
+	pub fn set_budget(&mut self, budget: &Budget) { self.budget = *budget }
+	
+	pub fn eta(&self) -> Eta { self.eta }
+	pub fn set_eta(&mut self, eta: Eta) { self.eta = eta }
+	
+	pub fn arithmetic(&self) -> Arithmetic { self.arithmetic }
+	pub fn set_arithmetic(&mut self, arithmetic: Arithmetic) { self.arithmetic = arithmetic }
+	
+	pub fn style(&self) -> Style { self.style }
+	pub fn set_style(&mut self, style: &Style) { self.style = *style }
+}
+
+impl Default for Parser {
+	fn default() -> Self { Self::new() }
 }
 
 impl Parser {
-	pub(crate) fn tokenize(&self, text: String, keyword_name: Option<&str>) -> Result<Vec<Token>> {
-		let mut tokens = Vec::<Token>::new();
+	// DOCS Every token is paired with the span of the source that it came from. Tokens added
+	// 		from a keyword share the span of the keyword's name, and the parentheses that are
+	// 		added around the whole expression share the span of the text that caused them.
+	pub(crate) fn tokenize(&self, text: String) -> Result<Vec<(Token, Span)>> {
+		let tokens = self.lex(text, None, &[])?;
+		
+		let mut out = Vec::with_capacity(tokens.len());
+		for (token, span) in tokens { self.link(token, span, None, &mut out)? }
+		
+		Ok(out)
+	}
+	
+	// DOCS Replaces a keyword reference with the current tokens of the keyword, which have
+	// 		the span of the reference.
+	fn link(&self, token: Token, span: Span, user: Option<&str>, out: &mut Vec<(Token, Span)>) -> Result<()> {
+		let Token::Keyword(name) = token else {
+			out.push((token, span));
+			return Ok(())
+		};
+		
+		if let Some(members) = self.groups.get(&name) { return self.link_recursive(&name, members, &[], user, span, out) }
+		
+		let Some(tokens) = self.keywords.get(&name) else {
+			return Err(LambdaError::MissingKeyword(name, user.unwrap_or_default().to_string(), span))
+		};
+		
+		for tk in tokens { self.link(tk.clone(), span, Some(&name), out)? }
+		
+		Ok(())
+	}
+	
+	// DOCS A recursive keyword `f` becomes `fix (λf.body)`, where `fix` is only unfolded once
+	// 		it's applied to an argument. Within a group, every use of a member that isn't bound
+	// 		yet is replaced by that member's own `fix`, so `even` becomes something like
+	// 		`fix (λeven.λn.... (fix (λodd.λn.... even ...)) ...)`. Applicative order and
+	// 		call-by-value reduce every argument before applying a function to it, so both
+	// 		branches of a condition like `is_null n 1 (mul n (fact (pred n)))` are reduced
+	// 		first, and the recursive call unfolds forever. Those keywords only finish under
+	// 		normal order and call-by-name.
+	fn link_recursive(&self, name: &str, members: &[String], bound: &[&str], user: Option<&str>, span: Span, out: &mut Vec<(Token, Span)>) -> Result<()> {
+		let Some(tokens) = self.keywords.get(name) else {
+			return Err(LambdaError::MissingKeyword(name.to_string(), user.unwrap_or_default().to_string(), span))
+		};
+		
+		let bound = [bound, &[name]].concat();
+		
+		out.push((Token::Open, span));
+		out.push((Token::Prim(Primitive::Fix), span));
+		out.push((Token::Open, span));
+		out.push((Token::Func(format!("${name}${name}")), span));
+		
+		for tk in tokens {
+			match tk {
+				Token::Keyword(member) if bound.contains(&member.as_str()) =>
+					out.push((Token::Var(format!("${member}${member}")), span)),
+				
+				Token::Keyword(member) if members.contains(member) =>
+					self.link_recursive(member, members, &bound, Some(name), span, out)?,
+				
+				_ => self.link(tk.clone(), span, Some(name), out)?,
+			}
+		}
+		
+		out.push((Token::Close, span));
+		out.push((Token::Close, span));
+		
+		Ok(())
+	}
+	
+	// DOCS The members of the group being defined are lexed as keywords, even before they
+	// 		exist, so that they can use themselves and each other.
+	fn lex(&self, text: String, keyword_name: Option<&str>, group: &[String]) -> Result<Vec<(Token, Span)>> {
+		let mut tokens = Vec::<(Token, Span)>::new();
 		let mut expecting_function_variable = false;
 		let mut expecting_function_point = false;
+		let mut expecting_let_variable = false;
+		let mut expecting_let_equals = false;
 		let mut closure_depth = 0;
+		let mut function_start = 0;
 		
-		for m in EXPRESSION_PARSER.find_iter(text.trim()) {
-			match m.as_str().trim() {
+		// The closure depth and start of every `let` that hasn't reached its `in` yet, and
+		// whether its variable has the name of the keyword being defined.
+		let mut lets = Vec::<(usize, usize, bool)>::new();
+		
+		// The closure depth of every function or `let` body that shadows the keyword being defined.
+		let mut shadows = Vec::<usize>::new();
+		
+		// Spans count characters rather than bytes, starting from the untrimmed text.
+		let trimmed = text.trim();
+		let mut byte_position = 0;
+		let mut char_position = text[..text.len() - text.trim_start().len()].chars().count();
+		
+		for m in EXPRESSION_PARSER.find_iter(trimmed) {
+			char_position += trimmed[byte_position..m.start()].chars().count();
+			byte_position = m.start();
+			
+			let span = Span::new(char_position, char_position + m.as_str().chars().count());
+			let word = m.as_str().trim();
+			
+			if expecting_let_equals && word != "=" {
+				return Err(LambdaError::IncompleteLet(Span::new(lets.last().map_or(0, |(_, start, _)| *start), span.end())))
+			}
+			if expecting_let_variable && (!VARIABLE_VALIDATOR.is_match(word) || word == "let" || word == "in") {
+				return Err(LambdaError::InvalidName(word.to_string(), span))
+			}
+			
+			match word {
 				"(" => {
-					if expecting_function_variable { return Err(LambdaError::InvalidName("(".to_string())); }
-					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('(')) }
+					if expecting_function_variable { return Err(LambdaError::InvalidName("(".to_string(), span)); }
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('(', span)) }
 					
 					closure_depth += 1;
-					tokens.push(Token::Open);
+					tokens.push((Token::Open, span));
 				}
 				
 				")" => {
-					if expecting_function_variable { return Err(LambdaError::InvalidName(")".to_string())); }
-					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter(')')) }
-					if closure_depth == 0 { return Err(LambdaError::UnmatchedParenthesis) }
+					if expecting_function_variable { return Err(LambdaError::InvalidName(")".to_string(), span)); }
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter(')', span)) }
+					if closure_depth == 0 { return Err(LambdaError::UnmatchedParenthesis(span)) }
+					if let Some((_, start, _)) = lets.last().filter(|(depth, _, _)| *depth == closure_depth) {
+						return Err(LambdaError::IncompleteLet(Span::new(*start, span.end())))
+					}
+					if let Some((Token::In, in_span)) = tokens.last() {
+						return Err(LambdaError::IncompleteLet(Span::new(in_span.start(), span.end())))
+					}
 					
 					closure_depth -= 1;
-					tokens.push(Token::Close);
+					shadows.retain(|depth| *depth <= closure_depth);
+					tokens.push((Token::Close, span));
 				}
 				
-				"λ" => {
-					if expecting_function_variable { return Err(LambdaError::InvalidName("λ".to_string())); }
-					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('λ')) }
-					if tokens.len() == 0 {
-						tokens.push(Token::Open);
+				// `\x.` can be typed without a `λ` key.
+				"λ" | "\\" => {
+					if expecting_function_variable { return Err(LambdaError::InvalidName(word.to_string(), span)); }
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter(word.chars().next().unwrap_or('λ'), span)) }
+					if tokens.is_empty() {
+						tokens.push((Token::Open, span));
 						closure_depth += 1;
 					}
 					
 					expecting_function_variable = true;
+					function_start = span.start();
 				}
 				
-				"." => {
-					if expecting_function_variable { return Err(LambdaError::InvalidName(".".to_string())) }
-					if !expecting_function_point { return Err(LambdaError::UnexpectedCharacter('.')) }
+				"." | "->" => {
+					if expecting_function_variable { return Err(LambdaError::InvalidName(word.to_string(), span)) }
+					if !expecting_function_point { return Err(LambdaError::UnexpectedCharacter(word.chars().next().unwrap_or('.'), span)) }
 					expecting_function_point = false;
 				}
 				
+				"let" => {
+					if expecting_function_variable { return Err(LambdaError::InvalidName("let".to_string(), span)) }
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('.', span)) }
+					if tokens.is_empty() {
+						tokens.push((Token::Open, span));
+						closure_depth += 1;
+					}
+					
+					lets.push((closure_depth, span.start(), false));
+					expecting_let_variable = true;
+				}
+				
+				"=" if expecting_let_equals => expecting_let_equals = false,
+				
+				// `let x = a; y = b in c` is the same as `let x = a in let y = b in c`.
+				"in" | ";" => {
+					if expecting_function_variable { return Err(LambdaError::InvalidName(word.to_string(), span)) }
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('.', span)) }
+					
+					let Some((depth, start, shadowing)) = lets.pop() else {
+						if word == ";" { return Err(LambdaError::UnexpectedCharacter(';', span)) }
+						return Err(LambdaError::UnmatchedIn(span))
+					};
+					
+					if depth != closure_depth { return Err(LambdaError::UnmatchedIn(span)) }
+					if let Some((Token::Let(_) | Token::In, _)) = tokens.last() {
+						return Err(LambdaError::IncompleteLet(Span::new(start, span.end())))
+					}
+					
+					tokens.push((Token::In, span));
+					if shadowing { shadows.push(closure_depth) }
+					
+					if word == ";" {
+						lets.push((closure_depth, span.start(), false));
+						expecting_let_variable = true;
+					}
+				}
+				
 				name => {
-					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('.')) }
-					if expecting_function_variable {
-						if !VARIABLE_VALIDATOR.is_match(name) { return Err(LambdaError::InvalidName(name.to_string())) }
+					// `λx y z.` is the same as `λx.λy.λz.`.
+					if expecting_function_point && VARIABLE_VALIDATOR.is_match(name) {
+						expecting_function_point = false;
+						expecting_function_variable = true;
+					}
+					
+					if expecting_function_point { return Err(LambdaError::UnexpectedCharacter('.', span)) }
+					if expecting_function_variable || expecting_let_variable {
+						if !VARIABLE_VALIDATOR.is_match(name) { return Err(LambdaError::InvalidName(name.to_string(), span)) }
+						if self.primitive(name).is_some() { return Err(LambdaError::ReservedName(name.to_string(), span)) }
 						
-						if let Some(key_name) = keyword_name {
-							if self.keywords.contains_key(name) && name != key_name {
-								return Err(LambdaError::ReservedName(name.to_string()))
-							}
-							tokens.push(Token::Func(format!("${key_name}${name}")));
-						} else {
-							if self.keywords.contains_key(name) {
-								return Err(LambdaError::ReservedName(name.to_string()))
-							}
-							tokens.push(Token::Func(name.to_owned()));
+						// A function or a `let` can use the name of the keyword it's in, which hides the
+						// keyword from its body. The bound expression of a `let` can still use the keyword.
+						if expecting_function_variable && keyword_name == Some(name) {
+							shadows.push(closure_depth);
+						} else if expecting_let_variable && keyword_name == Some(name) {
+							if let Some((_, _, shadowing)) = lets.last_mut() { *shadowing = true }
+						} else if self.keywords.contains_key(name) || group.iter().any(|n| n == name) {
+							return Err(LambdaError::ReservedName(name.to_string(), span))
 						}
 						
-						expecting_function_variable = false;
-						expecting_function_point = true;
-					} else if let Some(tks) = self.keywords.get(name) {
-						if let Some(key_name) = keyword_name && name == key_name { return Err(LambdaError::ActiveName(name.to_string())) }
-						tokens.append(&mut tks.clone());
-					} else if NUMBER_VALIDATOR.is_match(name) {
-						let Ok(num) = name.parse::<usize>() else { return Err(LambdaError::InternalFailure) };
-						if num > MAX_NUMBER_INPUT { return Err(LambdaError::NumberTooLarge(num)) }
+						let name = match keyword_name {
+							Some(key_name) => format!("${key_name}${name}"),
+							None => name.to_owned(),
+						};
 						
-						tokens.push(Token::Open);
-						tokens.push(Token::Func("$#$f".to_string()));
-						tokens.push(Token::Func("$#$x".to_string()));
-						for _ in 1..num {
-							tokens.push(Token::Var("$#$f".to_string()));
-							tokens.push(Token::Open);
+						if expecting_let_variable {
+							tokens.push((Token::Let(name), span));
+							expecting_let_variable = false;
+							expecting_let_equals = true;
+						} else {
+							tokens.push((Token::Func(name), span));
+							expecting_function_variable = false;
+							expecting_function_point = true;
+						}
+					} else if (self.keywords.contains_key(name) || group.iter().any(|n| n == name))
+						&& (shadows.is_empty() || keyword_name != Some(name)) {
+						tokens.push((Token::Keyword(name.to_string()), span));
+					} else if NUMBER_VALIDATOR.is_match(name) {
+						if tokens.is_empty() {
+							tokens.push((Token::Open, span));
+							closure_depth += 1;
 						}
 						
-						if num != 0 { tokens.push(Token::Var("$#$f".to_string())) }
-						tokens.push(Token::Var("$#$x".to_string()));
-						
-						for _ in 1..num {
-							tokens.push(Token::Close);
+						if self.arithmetic == Arithmetic::Native {
+							let Ok(value) = name.parse::<i64>() else { return Err(LambdaError::NumberTooLarge(name.to_string(), span)) };
+							tokens.push((Token::Int(value), span));
+						} else {
+							let Ok(value) = name.parse::<usize>() else { return Err(LambdaError::NumberTooLarge(name.to_string(), span)) };
+							tokens.push((Token::Num(value), span));
+						}
+					} else if let Some(op) = self.primitive(name) {
+						if tokens.is_empty() {
+							tokens.push((Token::Open, span));
+							closure_depth += 1;
 						}
 						
-						tokens.push(Token::Close);
+						tokens.push((Token::Prim(op), span));
 					} else if VARIABLE_VALIDATOR.is_match(name) {
-						if tokens.len() == 0 {
-							tokens.push(Token::Open);
+						if tokens.is_empty() {
+							tokens.push((Token::Open, span));
 							closure_depth += 1;
 						}
 						
 						if let Some(key_name) = keyword_name {
-							tokens.push(Token::Var(format!("${key_name}${name}")))
+							tokens.push((Token::Var(format!("${key_name}${name}")), span))
 						} else {
-							tokens.push(Token::Var(name.to_owned()));
+							tokens.push((Token::Var(name.to_owned()), span));
 						}
 					} else {
-						return Err(LambdaError::InvalidName(name.to_string()));
+						return Err(LambdaError::InvalidName(name.to_string(), span));
 					}
 				}
 			}
 		}
 		
-		if expecting_function_variable || expecting_function_point { return Err(LambdaError::IncompleteFunction) }
-		for _ in 0..closure_depth { tokens.push(Token::Close) }
+		char_position += trimmed[byte_position..].chars().count();
+		let end = Span::new(char_position, char_position);
+		
+		if expecting_function_variable || expecting_function_point {
+			return Err(LambdaError::IncompleteFunction(Span::new(function_start, char_position)))
+		}
+		if let Some((_, start, _)) = lets.last() {
+			return Err(LambdaError::IncompleteLet(Span::new(*start, char_position)))
+		}
+		if let Some((Token::In, in_span)) = tokens.last() {
+			return Err(LambdaError::IncompleteLet(Span::new(in_span.start(), char_position)))
+		}
+		for _ in 0..closure_depth { tokens.push((Token::Close, end)) }
 		
-		Ok(tokens)
+		Ok(desugar_lets(&tokens))
 	}
 	
-	fn add_keyword(&mut self, name: String, expression: String) -> Result<()> {
-		if name.trim().is_empty() || expression.trim().is_empty() { return Err(LambdaError::InternalFailure) }
-		if !KEYWORD_VALIDATOR.is_match(name.trim()) { return Err(LambdaError::InvalidKeyword(name)) }
+	// DOCS Keywords are checked before primitives, so a keyword can replace a primitive.
+	fn primitive(&self, name: &str) -> Option<Primitive> {
+		if self.arithmetic != Arithmetic::Native || self.keywords.contains_key(name) { return None }
+		Primitive::from_name(name)
+	}
+	
+	// DOCS A keyword can use itself, which makes it recursive. A member of a group that
+	// 		still uses the rest of its group is defined again along with the rest of the group.
+	pub fn define(&mut self, name: &str, expression: &str) -> Result<()> {
+		let result = self.define_group(&[(name, expression)]);
+		
+		let Err(LambdaError::CyclicKeyword(_, ref dependency, _)) = result else { return result };
+		let Some(members) = self.groups.get(name.trim()).filter(|members| members.contains(dependency)) else { return result };
+		
+		let definitions = members
+			.iter()
+			.map(|member| (member.clone(), if member == name.trim() { expression.to_string() } else { self.sources[member].clone() }))
+			.collect::<Vec<(String, String)>>();
+		
+		self.define_group(&definitions.iter().map(|(name, expression)| (name.as_str(), expression.as_str())).collect::<Vec<(&str, &str)>>())
+	}
+	
+	// DOCS Keywords in the same group can use each other. A group where no keyword uses any
+	// 		other keyword in the group is the same as defining each keyword on its own.
+	pub fn define_group(&mut self, definitions: &[(&str, &str)]) -> Result<()> {
+		let names = definitions.iter().map(|(name, _)| name.trim().to_string()).collect::<Vec<String>>();
+		let mut lexed = Vec::<(String, Vec<(Token, Span)>, HashSet<String>)>::with_capacity(names.len());
+		
+		for (name, (_, expression)) in names.iter().zip(definitions) {
+			if !KEYWORD_VALIDATOR.is_match(name) || RESERVED_WORDS.contains(&name.as_str()) { return Err(LambdaError::InvalidKeyword(name.clone())) }
+			if expression.trim().is_empty() { return Err(LambdaError::EmptyKeyword(name.clone())) }
+			if lexed.iter().any(|(n, _, _)| n == name) { return Err(LambdaError::DuplicateKeyword(name.clone())) }
+			
+			let tokens = self.lex(expression.to_string(), Some(name), &names)?;
+			if tokens.is_empty() { return Err(LambdaError::InvalidKeyword(name.clone())) };
+			
+			let dependents = self.dependents_of(name.clone());
+			let mut dependencies = HashSet::<String>::new();
+			
+			for (token, span) in tokens.iter() {
+				let Token::Keyword(dependency) = token else { continue };
+				
+				if dependents.contains(dependency) && !names.contains(dependency) {
+					return Err(LambdaError::CyclicKeyword(name.clone(), dependency.clone(), *span))
+				}
+				
+				dependencies.insert(dependency.clone());
+			}
+			
+			lexed.push((name.clone(), tokens, dependencies));
+		}
+		
+		for (name, (_, expression)) in names.iter().zip(definitions) {
+			self.sources.insert(name.clone(), expression.trim().to_string());
+		}
 		
-		let tokens = self.tokenize(expression, Some(name.trim()))?;
-		if tokens.is_empty() { return Err(LambdaError::InvalidKeyword(name)) };
+		let is_recursive = lexed.iter().any(|(_, _, dependencies)| names.iter().any(|n| dependencies.contains(n)));
 		
-		self.keywords.insert(name, tokens);
+		for (name, tokens, dependencies) in lexed {
+			self.leave_group(&name);
+			if is_recursive { self.groups.insert(name.clone(), names.clone()); }
+			
+			self.keywords.insert(name.clone(), tokens.into_iter().map(|(tk, _)| tk).collect());
+			self.dependencies.insert(name, dependencies);
+		}
+		
+		for name in names.iter() {
+			self.unindex_keyword(name);
+			self.index_keyword(name);
+			self.revalidate(name);
+		}
 		
 		Ok(())
 	}
+	
+	// DOCS The rest of the group stays recursive, and uses the keyword like any other keyword.
+	fn leave_group(&mut self, name: &str) {
+		let Some(members) = self.groups.remove(name) else { return };
+		
+		for member in members {
+			if let Some(group) = self.groups.get_mut(&member) { group.retain(|n| n != name) }
+		}
+	}
+	
+	// DOCS Indexes every keyword that uses the keyword again, since their normal forms change
+	// 		along with it, and they can't be indexed at all while it's missing.
+	fn revalidate(&mut self, name: &str) {
+		for dependent in self.dependents_of(name.to_string()) {
+			self.unindex_keyword(&dependent);
+			self.index_keyword(&dependent);
+		}
+	}
+	
+	fn index_keyword(&mut self, name: &str) {
+		let mut tokens = Vec::new();
+		if self.link(Token::Keyword(name.to_string()), Span::default(), None, &mut tokens).is_err() { return }
+		
+		// Keywords without a normal form, like `Y`, would otherwise use the whole budget.
+		let mut budget = self.budget;
+		budget.set_max_steps(Some(budget.max_steps().map_or(MAX_INDEX_STEPS, |steps| steps.min(MAX_INDEX_STEPS))));
+		
+		let Ok(mut expr) = builder::build(tokens) else { return };
+		if expr.reduce(Strategy::Normal, Eta::Off, &budget).is_err() { return }
+		
+		self.index.entry(expr.de_bruijn()).or_default().push(name.to_string());
+	}
+	
+	fn unindex_keyword(&mut self, name: &str) {
+		self.index.values_mut().for_each(|names| names.retain(|n| n != name));
+		self.index.retain(|_, names| !names.is_empty());
+	}
+	
+	pub(crate) fn keyword_index(&self) -> &KeywordIndex { &self.index }
+}
+
+// -- Desugaring -- //
+
+// DOCS `let x = a in b` becomes `((λx.b) (a))`, where `b` lasts until the end of the group
+// 		that the `let` is in, like the body of a function. The bound expression is copied
+// 		wherever `x` is used, so it isn't shared between uses.
+fn desugar_lets(tokens: &[(Token, Span)]) -> Vec<(Token, Span)> {
+	let mut out = Vec::with_capacity(tokens.len());
+	let mut index = 0;
+	
+	while index < tokens.len() {
+		let (Token::Let(name), span) = &tokens[index] else {
+			out.push(tokens[index].clone());
+			index += 1;
+			continue
+		};
+		
+		let mut nesting = 0;
+		let middle = index + tokens[index..].iter().position(|(token, _)| {
+			match token {
+				Token::Let(_) => nesting += 1,
+				Token::In => nesting -= 1,
+				_ => (),
+			}
+			
+			nesting == 0
+		}).unwrap_or(tokens.len() - index);
+		
+		let mut depth = 0;
+		let end = middle + tokens[middle..].iter().position(|(token, _)| {
+			match token {
+				Token::Open => depth += 1,
+				Token::Close => depth -= 1,
+				_ => (),
+			}
+			
+			depth < 0
+		}).unwrap_or(tokens.len() - middle);
+		
+		out.push((Token::Open, *span));
+		out.push((Token::Open, *span));
+		out.push((Token::Func(name.clone()), *span));
+		out.extend(desugar_lets(&tokens[(middle + 1).min(end)..end]));
+		out.push((Token::Close, *span));
+		out.push((Token::Open, *span));
+		out.extend(desugar_lets(&tokens[index + 1..middle]));
+		out.push((Token::Close, *span));
+		out.push((Token::Close, *span));
+		
+		index = end;
+	}
+	
+	out
 }
 
 // -- RegEx -- //
 
-static EXPRESSION_PARSER: LazyLock<Regex> = LazyLock::new(|| {
-	Regex::new(r"([()λ.]|[0-9]+|[+\-*/%^&|!?<>=]+|\S\w*)").unwrap()
+pub(crate) static EXPRESSION_PARSER: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"([()λ\\.]|->|[0-9]+|[+\-*/%^&|!?<>=]+|\S\w*)").unwrap()
 });
 
-static VARIABLE_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| {
+// DOCS Words that match `KEYWORD_VALIDATOR` but are part of the syntax.
+pub(crate) const RESERVED_WORDS: [&str; 3] = ["let", "in", "->"];
+
+pub(crate) static VARIABLE_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| {
 	Regex::new(r"\A[a-zA-Z]\w*\z").unwrap()
 });
 
@@ -191,3 +639,143 @@ static KEYWORD_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| {
 pub static KEYWORD_VARIABLE_TEST: LazyLock<Regex> = LazyLock::new(|| {
 	Regex::new(r"\A\$\S+?\$").unwrap()
 });
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::reduction::Status;
+	use crate::simplify;
+	use crate::term::Term;
+	
+	#[test]
+	fn function_variable_shadows_keyword() {
+		let mut parser = Parser::new();
+		
+		assert_eq!(parser.create_keyword("x".to_string(), "λx.x".to_string()), "λx.x");
+		assert_eq!(simplify("x".to_string(), &parser), "λx.x");
+		
+		assert_eq!(parser.create_keyword("x".to_string(), "λx.x x".to_string()), "λx.x x");
+		assert_eq!(simplify("x".to_string(), &parser), "λx.x x");
+		
+		// Only the body of the function is shadowed, so the second `f` is recursive.
+		parser.define("f", "(λf.f) f").unwrap();
+		assert!(parser.groups.contains_key("f"));
+		
+		assert!(matches!(parser.define("g", "λy.λg.g"), Ok(())));
+		assert!(matches!(parser.define("h", "λy.y"), Ok(())));
+		assert!(matches!(parser.define("k", "λh.h"), Err(LambdaError::ReservedName(..))));
+	}
+	
+	#[test]
+	fn group_member_redefined_alone() {
+		let mut parser = Parser::new();
+		parser.define_group(&[("a", "λx.b x"), ("b", "λx.a x")]).unwrap();
+		
+		parser.define("a", "λy.b (b y)").unwrap();
+		assert_eq!(parser.groups["a"], ["a", "b"]);
+		assert_eq!(parser.groups["b"], ["a", "b"]);
+		assert_eq!(parser.keyword_expression("a".to_string()).unwrap(), "λy.b (b y)");
+		assert_eq!(parser.keyword_expression("b".to_string()).unwrap(), "λx.a x");
+		
+		parser.define("a", "λy.y").unwrap();
+		assert!(!parser.groups.contains_key("a"));
+		assert_eq!(simplify("b z".to_string(), &parser), "z");
+	}
+	
+	#[test]
+	fn nested_lets_desugar_inside_out() {
+		let parser = Parser::new();
+		let parse = |expression: &str| Term::parse(expression, &parser).unwrap();
+		
+		assert_eq!(parse("let a = x in let b = a a in b b"), parse("(λa.(λb.b b) (a a)) x"));
+		assert_eq!(parse("let a = x; b = a a in b b"), parse("(λa.(λb.b b) (a a)) x"));
+		assert_eq!(parse("let a = let b = c in b b in a"), parse("(λa.a) ((λb.b b) c)"));
+		assert_eq!(simplify("let a = x in let b = a a in b b".to_string(), &parser), "x x (x x)");
+	}
+	
+	#[test]
+	fn let_body_ends_with_its_group() {
+		let parser = Parser::new();
+		let parse = |expression: &str| Term::parse(expression, &parser).unwrap();
+		
+		assert_eq!(parse("λy.let z = y y in z z"), parse("λy.(λz.z z) (y y)"));
+		assert_eq!(parse("(let a = b in a) c"), parse("(λa.a) b c"));
+		assert_eq!(simplify("λy.let z = y y in z z".to_string(), &parser), "λy.y y (y y)");
+	}
+	
+	#[test]
+	fn let_variable_only_shadows_the_keyword_being_defined() {
+		let mut parser = Parser::new();
+		parser.define("t", "λx.λy.x").unwrap();
+		
+		parser.define("f", "let f = t in f f").unwrap();
+		assert!(!parser.groups.contains_key("f"));
+		assert_eq!(simplify("f".to_string(), &parser), "λy.(λx.λy_1.x)");
+		
+		// The bound expression isn't shadowed, so `g` is recursive.
+		parser.define("g", "let g = λx.g in g").unwrap();
+		assert!(parser.groups.contains_key("g"));
+		
+		let error = Term::parse("let t = a in t", &parser).unwrap_err();
+		assert!(matches!(error, LambdaError::ReservedName(ref name, _) if name == "t"), "{error}");
+	}
+	
+	#[test]
+	fn let_without_in_is_incomplete() {
+		let parser = Parser::new();
+		
+		for (expression, end) in [("let a = b", 9), ("let a = b c", 11), ("(let a = b) c", 11)] {
+			let error = Term::parse(expression, &parser).unwrap_err();
+			assert!(matches!(error, LambdaError::IncompleteLet(_)), "{expression}: {error}");
+			assert_eq!(error.span().map(|span| span.end()), Some(end), "{expression}");
+		}
+		
+		assert!(matches!(Term::parse("a in b", &parser), Err(LambdaError::UnmatchedIn(_))));
+	}
+	
+	#[test]
+	fn removed_dependency_is_missing_until_redefined() {
+		let mut parser = Parser::new();
+		parser.define("a", "λx.x").unwrap();
+		parser.define("b", "λy.a y").unwrap();
+		parser.define("c", "λz.b z").unwrap();
+		
+		assert_eq!(parser.dependents_of("a".to_string()), ["b", "c"]);
+		assert_eq!(parser.dependents_of("c".to_string()), Vec::<String>::new());
+		let indexed = |parser: &Parser, name: &str| parser.index.values().any(|names| names.iter().any(|n| n == name));
+		assert!(indexed(&parser, "b") && indexed(&parser, "c"));
+		
+		parser.remove_keyword("a".to_string());
+		assert_eq!(parser.dependents_of("a".to_string()), ["b", "c"]);
+		assert!(!indexed(&parser, "b") && !indexed(&parser, "c"));
+		
+		for expression in ["b q", "c q"] {
+			let error = Term::parse(expression, &parser).unwrap_err();
+			assert!(matches!(error, LambdaError::MissingKeyword(ref missing, ref user, _) if missing == "a" && user == "b"), "{expression}: {error}");
+		}
+		
+		parser.define("a", "λx.x x").unwrap();
+		assert!(indexed(&parser, "b") && indexed(&parser, "c"));
+		assert_eq!(simplify("c q".to_string(), &parser), "q q");
+	}
+	
+	#[test]
+	fn recursion_only_finishes_under_lazy_strategies() {
+		let mut parser = Parser::with_prelude();
+		parser.define("fact", "λn.is_null n 1 (mul n (fact (pred n)))").unwrap();
+		
+		let mut budget = parser.budget();
+		budget.set_max_steps(Some(1_000));
+		parser.set_budget(&budget);
+		
+		let result = crate::evaluate("fact 3".to_string(), &parser, Strategy::Normal);
+		assert_eq!((result.status, result.output.as_str()), (Status::NormalForm, "λf.λx.f (f (f (f (f (f x)))))"));
+		
+		for strategy in [Strategy::Applicative, Strategy::CallByValue] {
+			let result = crate::evaluate("fact 3".to_string(), &parser, strategy);
+			assert_eq!(result.status, Status::BudgetExhausted, "{strategy}: {}", result.output);
+		}
+	}
+}
@@ -0,0 +1,73 @@
+// -- Imports -- //
+
+use crate::parser::Parser;
+use crate::reduction::Status;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// -- Files -- //
+
+// DOCS Each file can use the keywords of the files before it.
+const PRELUDE: [&str; 6] = [
+	include_str!("../prelude/booleans.lc"),
+	include_str!("../prelude/numerals.lc"),
+	include_str!("../prelude/comparisons.lc"),
+	include_str!("../prelude/pairs.lc"),
+	include_str!("../prelude/lists.lc"),
+	include_str!("../prelude/combinators.lc"),
+];
+
+// -- Loading -- //
+
+#[wasm_bindgen]
+impl Parser {
+	// DOCS A parser with the keywords of the prelude files, using Church arithmetic.
+	pub fn with_prelude() -> Self {
+		let mut parser = Self::new();
+		
+		for file in PRELUDE {
+			let results = parser.load_program(file.to_string());
+			debug_assert!(results.iter().all(|line| line.result.status == Status::NormalForm), "every line of the prelude is defined");
+		}
+		
+		parser
+	}
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::simplify;
+	
+	#[test]
+	fn every_definition_loads() {
+		let mut parser = Parser::new();
+		
+		for file in PRELUDE {
+			for line in parser.load_program(file.to_string()) {
+				assert_eq!(line.result.status, Status::NormalForm, "{:?}: {}", line.name, line.result.output);
+			}
+		}
+	}
+	
+	#[test]
+	fn examples_pass() {
+		let report = Parser::with_prelude().run_tests(include_str!("../prelude/examples.lc").to_string());
+		
+		assert_eq!(report.failed, 0, "{}", report.summary());
+		assert!(report.passed > 0);
+	}
+	
+	#[test]
+	fn lists_decode() {
+		let mut parser = Parser::with_prelude();
+		let mut style = parser.style();
+		style.set_decode(true);
+		parser.set_style(&style);
+		
+		assert_eq!(simplify("cons 1 (cons 2 nil)".to_string(), &parser), "[1, 2]");
+		assert_eq!(simplify("map succ (cons 1 (cons 2 nil))".to_string(), &parser), "[2, 3]");
+	}
+}
@@ -1,83 +1,588 @@
 // -- Imports -- //
 
-use crate::naming::{NameID, NameSpace};
-use crate::objects::{Expr, Object};
+use crate::debruijn::{self, DeBruijn};
+use crate::naming::{CopyID, NameID, NameSpace};
+use crate::objects::{Expr, Object, Redex};
+use crate::parser::KeywordIndex;
+use crate::term::Term;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+// -- Typing -- //
+
+type Vars = VecDeque<(NameID, Cell<CopyID>)>;
+
+// DOCS With `decode` set, Church numerals, booleans, pairs, and lists made of pairs ending in
+// 		`false` are printed as `5`, `true`, `(a, b)`, and `[1, 2, 3]`. The numeral zero and
+// 		`false` are the same term, so a whole result of `λt.λf.f` is printed as `false`, even
+// 		when it's a count like `sub 2 2`, and a zero inside of a pair or a list is printed as
+// 		`0`. With `fold_keywords` set, any function that is alpha-equivalent to the normal form
+// 		of a keyword is printed as the first keyword defined with that normal form. With
+// 		`ascii` set, functions are printed with `\` in place of `λ`, and with `compress_binders`
+// 		set, `λx.λy.b` is printed as `λx y.b`. Both forms can be read back by the parser. With
+// 		`minimal_parens` set, parentheses are only printed where they're needed, since
+// 		application is left-associative and functions extend as far right as they can. With a
+// 		`width`, terms that don't fit on one line are broken across indented lines.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Style {
+	decode: bool,
+	fold_keywords: bool,
+	ascii: bool,
+	compress_binders: bool,
+	minimal_parens: bool,
+	width: Option<usize>,
+}
+
+#[wasm_bindgen]
+impl Style {
+	pub fn new() -> Self { Self::default() }
+	
+	#[wasm_bindgen(getter)]
+	pub fn decode(&self) -> bool { self.decode }
+	#[wasm_bindgen(setter)]
+	pub fn set_decode(&mut self, decode: bool) { self.decode = decode }
+	
+	#[wasm_bindgen(getter)]
+	pub fn fold_keywords(&self) -> bool { self.fold_keywords }
+	#[wasm_bindgen(setter)]
+	pub fn set_fold_keywords(&mut self, fold_keywords: bool) { self.fold_keywords = fold_keywords }
+	
+	#[wasm_bindgen(getter)]
+	pub fn ascii(&self) -> bool { self.ascii }
+	#[wasm_bindgen(setter)]
+	pub fn set_ascii(&mut self, ascii: bool) { self.ascii = ascii }
+	
+	#[wasm_bindgen(getter)]
+	pub fn compress_binders(&self) -> bool { self.compress_binders }
+	#[wasm_bindgen(setter)]
+	pub fn set_compress_binders(&mut self, compress_binders: bool) { self.compress_binders = compress_binders }
+	
+	#[wasm_bindgen(getter)]
+	pub fn minimal_parens(&self) -> bool { self.minimal_parens }
+	#[wasm_bindgen(setter)]
+	pub fn set_minimal_parens(&mut self, minimal_parens: bool) { self.minimal_parens = minimal_parens }
+	
+	#[wasm_bindgen(getter)]
+	pub fn width(&self) -> Option<usize> { self.width }
+	#[wasm_bindgen(setter)]
+	pub fn set_width(&mut self, width: Option<usize>) { self.width = width }
+}
+
+// DOCS Where an object is printed, which decides whether it needs parentheses. `Last` is the
+// 		last argument of an application that nothing is printed after, so a function there
+// 		can't swallow anything by extending to the right.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Position {
+	Whole,
+	Body,
+	Head,
+	Argument,
+	Last,
+}
+
+const INDENT: usize = 2;
 
 // -- Exports -- //
 
-pub(crate) fn format(expr: &Expr) -> String {
-	let mut p = Printer {
-		active_copies: vec![0; expr.name_space.get_copies().len()],
-		local_ids: Vec::new(),
-	};
+pub(crate) fn format(expr: &Expr) -> String { format_with(expr, &Style::default(), &KeywordIndex::new()) }
+
+pub(crate) fn format_with(expr: &Expr, style: &Style, index: &KeywordIndex) -> String {
+	if style.decode && expr.de_bruijn() == DeBruijn::church_false() { return "false".to_string() }
 	
-	p.string_this(&expr.object, &expr.name_space, 0)
+	let doc = Printer::new(expr, style, index).doc_this(&expr.object, &expr.name_space, Position::Whole);
+	
+	match style.width {
+		Some(width) => doc.pretty(0, width),
+		None => doc.to_string(),
+	}
+}
+
+// DOCS Names the variables of the term the same way that `format` would.
+pub(crate) fn to_term(expr: &Expr) -> Term {
+	Printer::new(expr, &Style::default(), &KeywordIndex::new()).term_this(&expr.object, &expr.name_space)
+}
+
+// DOCS Formats a redex from `Expr::find_redex`, naming its variables the same way that they
+// 		would be named when formatting the entire expression.
+pub(crate) fn format_redex(expr: &Expr, redex: &Redex, style: &Style, index: &KeywordIndex) -> String {
+	let mut p = Printer::new(expr, style, index);
+	
+	match redex {
+		Redex::Beta(path) => p.string_redex(&expr.object, &expr.name_space, path, Some(2)),
+		Redex::Eta(path) => p.string_redex(&expr.object, &expr.name_space, path, None),
+		Redex::Delta(path) => {
+			let Some(Object::Func { vars: _, inner }) = expr.object.at(path) else { return String::new() };
+			let Some(Object::Prim { op }) = inner.front() else { return String::new() };
+			
+			p.string_redex(&expr.object, &expr.name_space, path, Some(op.arity() + 1))
+		}
+	}
 }
 
 // -- Printer Object -- //
 
-struct Printer {
+struct Printer<'a> {
+	style: Style,
+	index: &'a KeywordIndex,
 	active_copies: Vec<usize>,
 	local_ids: Vec<Vec<(NameID, usize)>>,
 }
 
-impl Printer {
-	fn string_this(&mut self, object: &Object, name_space: &NameSpace, depth: usize) -> String {
+impl<'a> Printer<'a> {
+	fn new(expr: &Expr, style: &Style, index: &'a KeywordIndex) -> Self {
+		Self {
+			style: *style,
+			index,
+			active_copies: vec![0; expr.name_space.get_copies().len()],
+			local_ids: Vec::new(),
+		}
+	}
+	
+	fn string_redex(&mut self, object: &Object, name_space: &NameSpace, path: &[usize], taken: Option<usize>) -> String {
+		// Eta redexes are whole functions, while other redexes are the first few objects of a closure.
+		if taken.is_none() && path.is_empty() { return self.doc_this(object, name_space, Position::Argument).to_string() }
+		
+		let Object::Func { vars, inner } = object else { return String::new() };
+		
+		self.push_locals(vars, name_space);
+		
+		let out = if let Some((index, path)) = path.split_first() {
+			self.string_redex(&inner[*index], name_space, path, taken)
+		} else {
+			inner
+				.iter()
+				.take(taken.unwrap_or_default())
+				.enumerate()
+				.map(|(i, o)| self.doc_this(o, name_space, if i == 0 { Position::Head } else { Position::Argument }).to_string())
+				.collect::<Vec<String>>()
+				.join(" ")
+		};
+		
+		self.pop_locals();
+		
+		out
+	}
+	
+	fn doc_this(&mut self, object: &Object, name_space: &NameSpace, position: Position) -> Doc {
 		match object {
 			Object::Term { name } => {
 				let true_id = name_space.get_absolute_name_index(*name);
-				name_space[true_id].clone()
+				Doc::Text(name_space[true_id].clone())
 			}
 			
-			Object::Var { depth, queue_position } => {
-				let index = self.local_ids.len() - 1 - depth;
-				let (name_id, suffix) = self.local_ids[index][*queue_position];
-				let out = name_space[name_id].clone();
-				if suffix != 0 { format!("{out}_{suffix}") }
-				else { out }
+			Object::Num { value } => {
+				if self.style.decode { return Doc::Text(value.to_string()) }
+				if self.style.fold_keywords && let Some(s) = self.string_keyword(object, name_space) { return Doc::Text(s) }
+				
+				let f = self.unused_name("f", name_space);
+				let x = self.unused_name("x", name_space);
+				
+				let binders = self.binders(&[f.as_str(), x.as_str()]);
+				
+				let body = match value {
+					0 => x,
+					n => format!("{}{f} {x}{}", format!("{f} (").repeat(n - 1), ")".repeat(n - 1)),
+				};
+				
+				self.wrap(Doc::Lambda(binders, Box::new(Doc::Text(body))), position, true)
 			}
 			
+			Object::Int { value } =>
+				Doc::Text(value.to_string()),
+			
+			Object::Prim { op } =>
+				Doc::Text(op.name().to_string()),
+			
+			Object::Var { depth, queue_position } =>
+				Doc::Text(self.local_name(*depth, *queue_position, name_space)),
+			
 			Object::Func { vars, inner } => {
-				let mut locals = Vec::<(NameID, usize)>::with_capacity(vars.len());
-				for (name_id, _) in vars {
-					let true_id = name_space.get_absolute_name_index(*name_id);
-					locals.push((true_id, self.active_copies[true_id]));
-					self.active_copies[true_id] += 1;
+				if self.style.decode && let Some(doc) = self.doc_decoded(object, name_space) { return doc }
+				if self.style.fold_keywords && let Some(s) = self.string_keyword(object, name_space) { return Doc::Text(s) }
+				
+				// Parentheses around a single object don't change anything.
+				if self.style.minimal_parens && vars.is_empty() && inner.len() == 1 && let Some(o) = inner.front() {
+					return self.doc_this(o, name_space, position)
 				}
 				
-				let mut position_counter = inner.len();
-				let mut out = Vec::<String>::with_capacity(position_counter + locals.len());
+				let is_function = !vars.is_empty();
+				let wrapped = self.wraps(position, is_function);
+				let extends_right = wrapped || matches!(position, Position::Whole | Position::Body | Position::Last);
 				
-				for (name_id, copy_id) in locals.iter() {
-					if *copy_id == 0 {
-						out.push(format!("λ{}.", &name_space[*name_id]));
-					} else {
-						out.push(format!("λ{}_{copy_id}.", &name_space[*name_id]));
-					}
-				}
+				self.push_locals(vars, name_space);
 				
-				// Removes the unnecessary trailing '.' if the function has no inner contents.
-				if inner.len() == 0 && let Some(s) = out.last_mut() { s.pop(); }
-				self.local_ids.push(locals);
+				let names = (0..vars.len()).map(|queue_position| self.local_name(0, queue_position, name_space)).collect::<Vec<String>>();
+				let mut binders = self.binders(&names.iter().map(String::as_str).collect::<Vec<&str>>());
 				
-				for o in inner {
-					position_counter -= 1;
+				let mut parts = Vec::<Doc>::with_capacity(inner.len());
+				let written = match Object::as_iteration(inner) {
+					Some((count, f, x)) => {
+						let extends_right = inner.len() == 3 && extends_right;
+						parts.push(self.doc_iteration(count, f, x, name_space, extends_right));
+						3
+					}
 					
-					let mut s = self.string_this(o, name_space, depth + 1);
-					if position_counter != 0 { s.push(' '); }
-					out.push(s);
+					None => 0,
+				};
+				
+				for (i, o) in inner.iter().enumerate().skip(written) {
+					let position = match i {
+						_ if is_function && inner.len() == 1 => Position::Body,
+						0 => Position::Head,
+						i if i + 1 == inner.len() && extends_right => Position::Last,
+						_ => Position::Argument,
+					};
+					
+					parts.push(self.doc_this(o, name_space, position));
 				}
 				
-				let locals = self.local_ids.pop().unwrap();
-				for name_id in locals {
-					self.active_copies[name_id.0] -= 1;
+				self.pop_locals();
+				
+				let body = if parts.len() == 1 { parts.remove(0) } else { Doc::Apply(parts) };
+				
+				let doc = if !is_function { body }
+				else if inner.is_empty() {
+					// Removes the unnecessary trailing '.' if the function has no inner contents.
+					binders.pop();
+					Doc::Text(binders)
 				}
+				else { Doc::Lambda(binders, Box::new(body)) };
+				
+				self.wrap(doc, position, is_function)
+			}
+		}
+	}
+	
+	// DOCS Writes out `n f x` as `f (f ... (f x))`, without recursing once for every application.
+	fn doc_iteration(&mut self, count: usize, f: &Object, x: &Object, name_space: &NameSpace, extends_right: bool) -> Doc {
+		let f = self.doc_this(f, name_space, Position::Head).to_string();
+		let x = self.doc_this(x, name_space, if count > 1 || extends_right { Position::Last } else { Position::Argument });
+		
+		Doc::Text(format!("{}{f} {x}{}", format!("{f} (").repeat(count - 1), ")".repeat(count - 1)))
+	}
+	
+	// DOCS Without minimal parentheses, everything but the whole term is parenthesized.
+	fn wraps(&self, position: Position, is_function: bool) -> bool {
+		if !self.style.minimal_parens { return position != Position::Whole }
+		
+		match position {
+			Position::Whole | Position::Body => false,
+			Position::Head => is_function,
+			Position::Argument => true,
+			Position::Last => !is_function,
+		}
+	}
+	
+	fn wrap(&self, doc: Doc, position: Position, is_function: bool) -> Doc {
+		if self.wraps(position, is_function) { Doc::Parens(Box::new(doc)) } else { doc }
+	}
+	
+	fn term_this(&mut self, object: &Object, name_space: &NameSpace) -> Term {
+		match object {
+			Object::Term { name } =>
+				Term::var(&name_space[name_space.get_absolute_name_index(*name)]),
+			
+			Object::Num { value } => {
+				let f = self.unused_name("f", name_space);
+				let x = self.unused_name("x", name_space);
+				let body = (0..*value).fold(Term::var(&x), |body, _| Term::app(Term::var(&f), body));
+				
+				Term::lam(f, Term::lam(x, body))
+			}
+			
+			Object::Int { value } =>
+				Term::Int(*value),
+			
+			Object::Prim { op } =>
+				Term::Prim(*op),
+			
+			Object::Var { depth, queue_position } =>
+				Term::Var(self.local_name(*depth, *queue_position, name_space)),
+			
+			Object::Func { vars, inner } => {
+				self.push_locals(vars, name_space);
 				
-				let out = if depth == 0 { format!("{}", out.into_iter().collect::<String>()) }
-				else { format!("({})", out.into_iter().collect::<String>()) };
+				let names = (0..vars.len()).map(|queue_position| self.local_name(0, queue_position, name_space)).collect::<Vec<String>>();
+				let body = match Object::as_iteration(inner) {
+					Some((count, f, x)) => {
+						let f = self.term_this(f, name_space);
+						let x = self.term_this(x, name_space);
+						let applied = (0..count).fold(x, |body, _| Term::app(f.clone(), body));
+						
+						Some(inner.iter().skip(3).map(|o| self.term_this(o, name_space)).fold(applied, Term::app))
+					}
+					
+					None => inner.iter().map(|o| self.term_this(o, name_space)).reduce(Term::app),
+				};
+				
+				self.pop_locals();
+				
+				// Functions without any inner contents only come from incomplete input.
+				names.into_iter().rev().fold(body.unwrap_or_else(|| Term::var("")), |body, name| Term::lam(name, body))
+			}
+		}
+	}
+	
+	// DOCS Writes the variables of a function as `λx.λy.`, or as `λx y.` with compressed binders.
+	fn binders(&self, names: &[&str]) -> String {
+		let lambda = if self.style.ascii { "\\" } else { "λ" };
+		
+		if self.style.compress_binders && !names.is_empty() { format!("{lambda}{}.", names.join(" ")) }
+		else { names.iter().map(|name| format!("{lambda}{name}.")).collect() }
+	}
+	
+	// DOCS Variables that share a name with a variable of an enclosing function are suffixed
+	// 		with the number of functions between them.
+	fn local_name(&self, depth: usize, queue_position: usize, name_space: &NameSpace) -> String {
+		let (name_id, suffix) = self.local_ids[self.local_ids.len() - 1 - depth][queue_position];
+		
+		if suffix == 0 { name_space[name_id].clone() }
+		else { format!("{}_{suffix}", name_space[name_id]) }
+	}
+	
+	// DOCS Numerals aren't made of real functions, so their variable names are suffixed
+	// 		by hand to avoid the names of any functions that they are inside of.
+	fn unused_name(&self, name: &str, name_space: &NameSpace) -> String {
+		let copies = self.local_ids
+			.iter()
+			.flatten()
+			.filter(|(name_id, _)| name_space[*name_id] == name)
+			.count();
+		
+		if copies == 0 { name.to_string() }
+		else { format!("{name}_{copies}") }
+	}
+	
+	fn push_locals(&mut self, vars: &Vars, name_space: &NameSpace) {
+		let mut locals = Vec::<(NameID, usize)>::with_capacity(vars.len());
+		for (name_id, _) in vars {
+			let true_id = name_space.get_absolute_name_index(*name_id);
+			locals.push((true_id, self.active_copies[true_id]));
+			self.active_copies[true_id] += 1;
+		}
+		
+		self.local_ids.push(locals);
+	}
+	
+	fn pop_locals(&mut self) {
+		let locals = self.local_ids.pop().unwrap();
+		for name_id in locals {
+			self.active_copies[name_id.0] -= 1;
+		}
+	}
+}
+
+// -- Decoding -- //
+
+impl Printer<'_> {
+	fn string_keyword(&self, object: &Object, name_space: &NameSpace) -> Option<String> {
+		self.index.get(&self.de_bruijn(object, name_space))?.first().cloned()
+	}
+	
+	fn doc_decoded(&mut self, object: &Object, name_space: &NameSpace) -> Option<Doc> {
+		let form = self.de_bruijn(object, name_space);
+		
+		if let DeBruijn::Num(n) = form { return Some(Doc::Text(n.to_string())) }
+		if form == DeBruijn::church_true() { return Some(Doc::Text("true".to_string())) }
+		
+		let (vars, first, second) = as_pair(object)?;
+		self.push_locals(vars, name_space);
+		
+		let out = if let Some(items) = self.as_list(second, name_space) {
+			let mut items = items
+				.into_iter()
+				.map(|(vars, o)| {
+					self.push_locals(vars, name_space);
+					self.doc_this(o, name_space, Position::Whole)
+				})
+				.collect::<Vec<Doc>>();
+			
+			items.iter().for_each(|_| self.pop_locals());
+			items.insert(0, self.doc_this(first, name_space, Position::Whole));
+			
+			Doc::Sequence('[', items, ']')
+		} else {
+			let first = self.doc_this(first, name_space, Position::Whole);
+			Doc::Sequence('(', vec![first, self.doc_this(second, name_space, Position::Whole)], ')')
+		};
+		
+		self.pop_locals();
+		
+		Some(out)
+	}
+	
+	// DOCS Collects the first object of every pair in a chain of pairs ending in `false`,
+	// 		along with the variables of the function that each object is inside of.
+	fn as_list<'a>(
+		&mut self,
+		mut object: &'a Object,
+		name_space: &NameSpace
+	) -> Option<Vec<(&'a Vars, &'a Object)>> {
+		let mut items = Vec::new();
+		
+		loop {
+			if self.de_bruijn_within(object, name_space, items.len()) == DeBruijn::church_false() { return Some(items) }
+			
+			let (vars, first, second) = as_pair(object)?;
+			items.push((vars, first));
+			object = second;
+		}
+	}
+	
+	fn de_bruijn(&self, object: &Object, name_space: &NameSpace) -> DeBruijn {
+		self.de_bruijn_within(object, name_space, 0)
+	}
+	
+	// DOCS `pairs` counts the pairs between the printer's current position and the object.
+	fn de_bruijn_within(&self, object: &Object, name_space: &NameSpace, pairs: usize) -> DeBruijn {
+		let mut levels = self.local_ids.iter().map(Vec::len).collect::<Vec<usize>>();
+		levels.extend((0..pairs).map(|_| 1));
+		
+		debruijn::convert(object, name_space, &mut levels)
+	}
+}
+
+// DOCS Pairs are functions of the form `λf.f a b` where `f` doesn't occur in `a` or `b`.
+fn as_pair(object: &Object) -> Option<(&Vars, &Object, &Object)> {
+	let Object::Func { vars, inner } = object else { return None };
+	
+	if vars.len() != 1 || inner.len() != 3 { return None }
+	let Object::Var { depth: 0, queue_position: 0 } = inner[0] else { return None };
+	if inner[1].mentions(0, 0) || inner[2].mentions(0, 0) { return None }
+	
+	Some((vars, &inner[1], &inner[2]))
+}
+
+// -- Layout -- //
+
+// DOCS Printed terms are kept as a tree until the end, so that the pretty printer knows where
+// 		lines can be broken. On one line, they're printed the same way as they always were.
+enum Doc {
+	Text(String),
+	Lambda(String, Box<Doc>),
+	Apply(Vec<Doc>),
+	Parens(Box<Doc>),
+	Sequence(char, Vec<Doc>, char),
+}
+
+impl Display for Doc {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Text(s) => write!(f, "{s}"),
+			Self::Lambda(binders, body) => write!(f, "{binders}{body}"),
+			Self::Parens(inner) => write!(f, "({inner})"),
+			Self::Apply(parts) => write_separated(f, parts, " "),
+			Self::Sequence(open, items, close) => {
+				write!(f, "{open}")?;
+				write_separated(f, items, ", ")?;
+				write!(f, "{close}")
+			}
+		}
+	}
+}
+
+fn write_separated(f: &mut Formatter<'_>, docs: &[Doc], separator: &str) -> std::fmt::Result {
+	for (i, doc) in docs.iter().enumerate() {
+		if i != 0 { write!(f, "{separator}")? }
+		write!(f, "{doc}")?;
+	}
+	
+	Ok(())
+}
+
+impl Doc {
+	// DOCS Anything that doesn't fit in the rest of the line is broken up. A function's body
+	// 		and the arguments of an application go on their own lines, one indent further in
+	// 		than where the function or application started. `column` is where the doc starts.
+	fn pretty(&self, column: usize, width: usize) -> String {
+		let flat = self.to_string();
+		if column + flat.chars().count() <= width { return flat }
+		
+		let indent = " ".repeat(column + INDENT);
+		
+		match self {
+			Self::Text(_) => flat,
+			Self::Lambda(binders, body) => format!("{binders}\n{indent}{}", body.pretty(column + INDENT, width)),
+			Self::Parens(inner) => format!("({})", inner.pretty(column + 1, width)),
+			
+			Self::Apply(parts) => {
+				let Some((head, arguments)) = parts.split_first() else { return flat };
+				let mut out = head.pretty(column, width);
+				
+				for argument in arguments {
+					out.push('\n');
+					out.push_str(&indent);
+					out.push_str(&argument.pretty(column + INDENT, width));
+				}
 				
 				out
 			}
+			
+			Self::Sequence(open, items, close) => {
+				let separator = format!(",\n{}", " ".repeat(column + 1));
+				let items = items.iter().map(|item| item.pretty(column + 1, width)).collect::<Vec<String>>();
+				
+				format!("{open}{}{close}", items.join(&separator))
+			}
 		}
 	}
 }
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::evaluate;
+	use crate::parser::Parser;
+	use crate::reduction::Strategy;
+	
+	fn decoded(expression: &str) -> String {
+		let mut style = Style::new();
+		style.set_decode(true);
+		
+		let mut parser = Parser::with_prelude();
+		parser.set_style(&style);
+		
+		evaluate(expression.to_string(), &parser, Strategy::default()).output
+	}
+	
+	#[test]
+	fn whole_false_is_decoded_as_false() {
+		assert_eq!(decoded("and true false"), "false");
+		assert_eq!(decoded("is_null 1"), "false");
+		assert_eq!(decoded("is_null 0"), "true");
+		assert_eq!(decoded("sub 2 2"), "false");
+		assert_eq!(decoded("pair 0 1"), "(0, 1)");
+		assert_eq!(decoded("cons 0 (cons 1 nil)"), "[0, 1]");
+	}
+	
+	#[test]
+	fn keywords_are_folded_only_when_asked() {
+		let mut style = Style::new();
+		style.set_fold_keywords(true);
+		
+		let mut parser = Parser::with_prelude();
+		parser.set_style(&style);
+		
+		let folded = |expression: &str, parser: &Parser| evaluate(expression.to_string(), parser, Strategy::default()).output;
+		
+		assert_eq!(folded("not true", &parser), "false");
+		assert_eq!(folded("(λx.x) add", &parser), "add");
+		assert_eq!(folded("K", &parser), "true");
+		assert_eq!(folded("pair true (λy.y)", &parser), "λf.f true I");
+		assert_eq!(folded("λf.f (succ 0)", &parser), "λf.f (λf_1.λx.f_1 x)");
+		
+		style.set_fold_keywords(false);
+		parser.set_style(&style);
+		
+		assert_eq!(folded("not true", &parser), "λx.λy.y");
+		assert_eq!(folded("pair true (λy.y)", &parser), "λf.f (λx.λy.x) (λy.y)");
+	}
+}
+
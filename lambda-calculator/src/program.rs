@@ -0,0 +1,306 @@
+// -- Imports -- //
+
+use crate::builder;
+use crate::equivalence::{equivalent, Equivalence};
+use crate::errors::{LambdaError, Result, Span};
+use crate::parser::{Parser, EXPRESSION_PARSER};
+use crate::printer;
+use crate::reduction::{EvalResult, Status, Strategy};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// -- Statements -- //
+
+// DOCS One line of a program without its comment. The offsets are the number of characters
+// 		in the program before each expression, which are added to the spans of its errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Statement<'a> {
+	Definition { name: &'a str, expression: &'a str, offset: usize },
+	Evaluation { expression: &'a str, offset: usize },
+	Assertion { left: (&'a str, usize), relation: Option<Relation>, right: (&'a str, usize), span: Span },
+}
+
+// DOCS `==` holds when both sides have the same normal form, and `normalizes_to` holds when
+// 		the normal form of the left side is the right side as it's written.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Relation {
+	Equal,
+	NormalizesTo,
+}
+
+// DOCS Splits a program into its statements, along with their line numbers, skipping lines
+// 		that are blank or only a comment. A comment starts at a token made only of two or
+// 		more dashes, so a keyword named `--` can't be used in a program.
+pub(crate) fn statements(text: &str) -> Vec<(usize, Statement<'_>)> {
+	let mut statements = Vec::new();
+	let mut line_start = 0;
+	
+	for (line_index, line) in text.split('\n').enumerate() {
+		let offset = line_start;
+		line_start += line.chars().count() + 1;
+		
+		let code = EXPRESSION_PARSER
+			.find_iter(line)
+			.find(|m| m.as_str().len() >= 2 && m.as_str().chars().all(|c| c == '-'))
+			.map_or(line, |m| &line[..m.start()]);
+		
+		if code.trim().is_empty() { continue }
+		
+		let mut tokens = EXPRESSION_PARSER.find_iter(code);
+		let statement = match (tokens.next(), tokens.next()) {
+			(Some(keyword), _) if keyword.as_str() == "assert" => assertion(code, keyword.end(), offset),
+			
+			(Some(name), Some(equals)) if equals.as_str() == "=" && name.as_str() != "let" => Statement::Definition {
+				name: name.as_str(),
+				expression: &code[equals.end()..],
+				offset: offset + code[..equals.end()].chars().count(),
+			},
+			
+			_ => Statement::Evaluation { expression: code, offset },
+		};
+		
+		statements.push((line_index + 1, statement));
+	}
+	
+	statements
+}
+
+// DOCS The sides of an assertion are split at the first `==` or `normalizes_to` outside of
+// 		any parentheses, so the `==` primitive has to be in parentheses on the left side.
+fn assertion(code: &str, start: usize, offset: usize) -> Statement<'_> {
+	let span = Span::new(
+		offset + code[..code.len() - code.trim_start().len()].chars().count(),
+		offset + code.trim_end().chars().count(),
+	);
+	
+	let mut depth = 0usize;
+	let separator = EXPRESSION_PARSER.find_iter(&code[start..]).find(|m| {
+		match m.as_str() {
+			"(" => depth += 1,
+			")" => depth = depth.saturating_sub(1),
+			_ => (),
+		}
+		
+		depth == 0 && (m.as_str() == "==" || m.as_str() == "normalizes_to")
+	});
+	
+	let Some(separator) = separator else {
+		return Statement::Assertion { left: (&code[start..], offset), relation: None, right: ("", offset), span }
+	};
+	
+	let (middle, end) = (start + separator.start(), start + separator.end());
+	let relation = if separator.as_str() == "==" { Relation::Equal } else { Relation::NormalizesTo };
+	
+	Statement::Assertion {
+		left: (&code[start..middle], offset + code[..start].chars().count()),
+		relation: Some(relation),
+		right: (&code[end..], offset + code[..end].chars().count()),
+		span,
+	}
+}
+
+// -- Results -- //
+
+// DOCS The result of one line of a program. Definitions have the name that they defined,
+// 		and their result holds the expression like `define_keyword`. Assertions say whether
+// 		they passed, and their result holds the assertion when they do.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineResult {
+	pub line: usize,
+	pub name: Option<String>,
+	pub passed: Option<bool>,
+	pub result: EvalResult,
+}
+
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestFailure {
+	pub line: usize,
+	pub message: String,
+	pub span: Option<Span>,
+}
+
+// DOCS Every line that fails is a failure, including definitions and evaluations, but only
+// 		assertions count as passing.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestReport {
+	pub passed: usize,
+	pub failed: usize,
+	pub failures: Vec<TestFailure>,
+}
+
+#[wasm_bindgen]
+impl TestReport {
+	// DOCS Lists the failures, with carets under their spans, followed by the totals.
+	pub fn summary(&self) -> String {
+		let mut out = String::new();
+		for failure in &self.failures { out += &format!("line {}: {}\n\n", failure.line, failure.message) }
+		
+		out + &format!("{} passed, {} failed", self.passed, self.failed)
+	}
+}
+
+// -- Loading -- //
+
+#[wasm_bindgen]
+impl Parser {
+	pub fn load_program(&mut self, text: String) -> Vec<LineResult> {
+		self.load_program_with(text, Strategy::default())
+	}
+	
+	// DOCS Defines and evaluates every line in order, so an expression can only use the
+	// 		keywords above it. A line that fails doesn't stop the lines after it.
+	pub fn load_program_with(&mut self, text: String, strategy: Strategy) -> Vec<LineResult> {
+		self.load_program_each(text, strategy, |_, _, _| ())
+	}
+	
+	pub fn run_tests(&mut self, text: String) -> TestReport {
+		self.run_tests_with(text, Strategy::default())
+	}
+	
+	pub fn run_tests_with(&mut self, text: String, strategy: Strategy) -> TestReport {
+		let results = self.load_program_with(text.clone(), strategy);
+		
+		let failures = results
+			.iter()
+			.filter(|line| line.result.status != Status::NormalForm)
+			.map(|line| TestFailure {
+				line: line.line,
+				message: line.result.underline(&text),
+				span: line.result.span,
+			})
+			.collect::<Vec<TestFailure>>();
+		
+		TestReport {
+			passed: results.iter().filter(|line| line.passed == Some(true)).count(),
+			failed: failures.len(),
+			failures,
+		}
+	}
+}
+
+impl Parser {
+	// DOCS Loads the program like `load_program_with`, and calls `each` with the result of every
+	// 		line as soon as it's done, along with the expression when the line is an evaluation.
+	// 		The parser it gets only has the keywords defined up to that line.
+	pub fn load_program_each(&mut self, text: String, strategy: Strategy, mut each: impl FnMut(&Parser, &LineResult, Option<&str>)) -> Vec<LineResult> {
+		let mut results = Vec::new();
+		
+		for (line, statement) in statements(&text) {
+			let (result, evaluated) = match statement {
+				Statement::Definition { name, expression, offset } => {
+					let defined = self.define(name, expression).map(|_| expression.trim().to_string());
+					
+					(LineResult { line, name: Some(name.to_string()), passed: None, result: EvalResult::new(defined, 0, 0).offset(offset) }, None)
+				}
+				
+				Statement::Evaluation { expression, offset } => {
+					let result = crate::evaluate(expression.to_string(), self, strategy);
+					
+					(LineResult { line, name: None, passed: None, result: result.offset(offset) }, Some(expression))
+				}
+				
+				Statement::Assertion { left, relation, right, span } => {
+					let result = self.check(left, relation, right, span, strategy);
+					
+					(LineResult { line, name: None, passed: Some(result.status == Status::NormalForm), result }, None)
+				}
+			};
+			
+			each(self, &result, evaluated);
+			results.push(result);
+		}
+		
+		results
+	}
+	
+	// DOCS `==` uses `equivalent`, which always reduces in normal order, while `normalizes_to`
+	// 		reduces the left side with the given strategy.
+	fn check(&self, left: (&str, usize), relation: Option<Relation>, right: (&str, usize), span: Span, strategy: Strategy) -> EvalResult {
+		let Some(relation) = relation.filter(|_| !left.0.trim().is_empty() && !right.0.trim().is_empty()) else {
+			return EvalResult::new(Err(LambdaError::IncompleteAssertion(span)), 0, 0)
+		};
+		
+		// Each side is built on its own first, so that the spans of its errors can be moved.
+		let mut sides = Vec::with_capacity(2);
+		for (expression, offset) in [left, right] {
+			match self.tokenize(expression.to_string()).and_then(builder::build) {
+				Ok(expr) => sides.push(expr),
+				Err(e) => return EvalResult::new(Err(e), 0, 0).offset(offset),
+			}
+		}
+		
+		let (l, r) = (left.0.trim(), right.0.trim());
+		let passed = format!("{l} {} {r}", if relation == Relation::Equal { "==" } else { "normalizes_to" });
+		
+		let result: Result<String> = match relation {
+			Relation::Equal => equivalent(l.to_string(), r.to_string(), self).and_then(|equivalence| match equivalence {
+				Equivalence::AlphaEquivalent | Equivalence::BetaEquivalent => Ok(passed),
+				Equivalence::Different => Err(LambdaError::AssertionFailed(format!("\"{l}\" is not equal to \"{r}\""), span)),
+				Equivalence::Undecided =>
+					Err(LambdaError::AssertionFailed(format!("couldn't decide whether \"{l}\" is equal to \"{r}\" within the budget"), span)),
+			}),
+			
+			Relation::NormalizesTo => {
+				let expected = sides.pop().map(|expr| expr.de_bruijn());
+				let Some(mut expr) = sides.pop() else { return EvalResult::new(Err(LambdaError::InternalFailure), 0, 0) };
+				
+				expr.reduce(strategy, self.eta(), &self.budget()).and_then(|_| {
+					if Some(expr.de_bruijn()) == expected { return Ok(passed) }
+					
+					let normal = printer::format_with(&expr, &self.style(), self.keyword_index());
+					Err(LambdaError::AssertionFailed(format!("\"{l}\" normalizes to \"{normal}\", not \"{r}\""), span))
+				})
+			}
+		};
+		
+		EvalResult::new(result, 0, 0)
+	}
+}
+
+impl EvalResult {
+	fn offset(mut self, offset: usize) -> Self {
+		self.span = self.span.map(|span| Span::new(span.start() + offset, span.end() + offset));
+		self
+	}
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	fn spanned(text: &str, span: Span) -> String { text.chars().skip(span.start()).take(span.end() - span.start()).collect() }
+	
+	#[test]
+	fn failed_assertions_report_their_line_and_span() {
+		let text = "-- λ identities\nid = λx.x\n  assert id a == a -- passes\n\nassert id λy.y == λx.λy.x  -- fails\nassert a == λz.$b\n";
+		let report = Parser::new().run_tests(text.to_string());
+		assert_eq!((report.passed, report.failed), (1, 2));
+		
+		let TestFailure { line, ref message, span } = report.failures[0];
+		assert_eq!((line, span), (5, Some(Span::new(56, 81))));
+		assert_eq!(spanned(text, span.unwrap()), "assert id λy.y == λx.λy.x");
+		assert_eq!(message, "assertion error: \"id λy.y\" is not equal to \"λx.λy.x\"\nassert id λy.y == λx.λy.x  -- fails\n^^^^^^^^^^^^^^^^^^^^^^^^^");
+		
+		// An error inside of one side is underlined where it is, rather than the whole assertion.
+		let TestFailure { line, span, .. } = report.failures[1];
+		assert_eq!(line, 6);
+		assert_eq!(spanned(text, span.unwrap()), "$b");
+	}
+	
+	#[test]
+	fn each_line_sees_only_the_keywords_above_it() {
+		let text = "a = x\n\nb a -- uses a\nb = y\nb a";
+		let mut seen = Vec::new();
+		
+		Parser::new().load_program_each(text.to_string(), Strategy::default(), |parser, line, evaluated| {
+			seen.push((line.line, evaluated.map(|e| e.trim().to_string()), parser.keyword_expression("b".to_string()).is_some()));
+		});
+		
+		assert_eq!(seen, [(1, None, false), (3, Some("b a".to_string()), false), (4, None, true), (5, Some("b a".to_string()), true)]);
+	}
+}
@@ -0,0 +1,488 @@
+// -- Imports -- //
+
+use crate::{builder, printer};
+use crate::debruijn::DeBruijn;
+use crate::{DEFAULT_MAX_SIZE, DEFAULT_MAX_STEPS, DEFAULT_MAX_TIME, MAX_OBJECT_DEPTH};
+use crate::errors::{self, ErrorKind, LambdaError, Result, Span};
+use crate::objects::Expr;
+use crate::parser::{KeywordIndex, Parser};
+use crate::printer::Style;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// -- Strategy -- //
+
+// DOCS Normal order and applicative order reduce to a full normal form, while call-by-name
+// 		and call-by-value stop at a weak head normal form and a weak normal form respectively,
+// 		so only the first two reduce `λx.(λy.y) x` to `λx.x`. Applicative order and call-by-value
+// 		reduce arguments before applying functions to them, so they never finish terms like
+// 		`(λx.y) ((λx.x x) (λx.x x))` that normal order and call-by-name reduce to `y`.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Strategy {
+	#[default]
+	Normal,
+	Applicative,
+	CallByName,
+	CallByValue,
+}
+
+impl Strategy {
+	pub(crate) fn is_weak(self) -> bool { matches!(self, Self::CallByName | Self::CallByValue) }
+}
+
+impl Display for Strategy {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Normal => write!(f, "normal"),
+			Self::Applicative => write!(f, "applicative"),
+			Self::CallByName => write!(f, "call-by-name"),
+			Self::CallByValue => write!(f, "call-by-value"),
+		}
+	}
+}
+
+impl FromStr for Strategy {
+	type Err = String;
+	
+	fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+		match s {
+			"normal" => Ok(Self::Normal),
+			"applicative" => Ok(Self::Applicative),
+			"call-by-name" | "cbn" => Ok(Self::CallByName),
+			"call-by-value" | "cbv" => Ok(Self::CallByValue),
+			_ => Err(format!("unknown strategy \"{s}\", expected normal, applicative, call-by-name or call-by-value")),
+		}
+	}
+}
+
+// -- Eta -- //
+
+// DOCS `Reduce` also performs eta reductions to reach beta-eta normal forms, and `Expand`
+// 		performs eta expansions on the normal form to reach an eta-long form.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Eta {
+	#[default]
+	Off,
+	Reduce,
+	Expand,
+}
+
+// -- Arithmetic -- //
+
+// DOCS With `Native` set, number literals are integers rather than Church numerals, and the
+// 		operators `+ - * / % == != < > <= >=` and `if` are primitives that reduce by delta
+// 		rules. Keywords are tokenized when they're created, so they keep the arithmetic that
+// 		was set at the time.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Arithmetic {
+	#[default]
+	Church,
+	Native,
+}
+
+// -- Budget -- //
+
+// DOCS Limits on a single reduction. Every limit can be removed by setting it to `None`,
+// 		and the time limit is measured in milliseconds.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Budget {
+	max_steps: Option<usize>,
+	max_size: Option<usize>,
+	max_time: Option<f64>,
+}
+
+#[wasm_bindgen]
+impl Budget {
+	pub fn new() -> Self {
+		Self {
+			max_steps: Some(DEFAULT_MAX_STEPS),
+			max_size: Some(DEFAULT_MAX_SIZE),
+			max_time: Some(DEFAULT_MAX_TIME),
+		}
+	}
+	
+	pub fn unlimited() -> Self { Self { max_steps: None, max_size: None, max_time: None } }
+	
+	#[wasm_bindgen(getter)]
+	pub fn max_steps(&self) -> Option<usize> { self.max_steps }
+	#[wasm_bindgen(setter)]
+	pub fn set_max_steps(&mut self, max_steps: Option<usize>) { self.max_steps = max_steps }
+	
+	#[wasm_bindgen(getter)]
+	pub fn max_size(&self) -> Option<usize> { self.max_size }
+	#[wasm_bindgen(setter)]
+	pub fn set_max_size(&mut self, max_size: Option<usize>) { self.max_size = max_size }
+	
+	#[wasm_bindgen(getter)]
+	pub fn max_time(&self) -> Option<f64> { self.max_time }
+	#[wasm_bindgen(setter)]
+	pub fn set_max_time(&mut self, max_time: Option<f64>) { self.max_time = max_time }
+}
+
+impl Default for Budget {
+	fn default() -> Self { Self::new() }
+}
+
+impl Budget {
+	pub(crate) fn start(&self) -> Meter {
+		Meter { budget: *self, steps: 0, started: milliseconds(), visited: HashMap::new(), repeated: HashMap::new() }
+	}
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Limit {
+	Steps(usize),
+	Size(usize),
+	Time(f64),
+}
+
+impl Display for Limit {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Steps(n) => write!(f, "step limit of {n} beta reductions"),
+			Self::Size(n) => write!(f, "size limit of {n} terms"),
+			Self::Time(ms) => write!(f, "time limit of {ms}ms"),
+		}
+	}
+}
+
+// DOCS Tracks how much of a budget has been spent. `step` is called before every beta
+// 		reduction and fails with the partially reduced expression once a limit is reached.
+// 		It also remembers the fingerprint of every expression it has seen, so that reductions
+// 		which return to an alpha-equivalent expression are stopped once they repeat. Two
+// 		different expressions can share a fingerprint, so when one comes back, only its form
+// 		is kept, and the reduction is stopped when that exact form comes back again.
+#[derive(Clone, Debug)]
+pub(crate) struct Meter {
+	budget: Budget,
+	steps: usize,
+	started: f64,
+	visited: HashMap<u64, usize>,
+	repeated: HashMap<u64, (DeBruijn, usize)>,
+}
+
+impl Meter {
+	pub fn steps(&self) -> usize { self.steps }
+	
+	pub fn step(&mut self, expr: &Expr) -> Result<()> {
+		if expr.object.depth() > MAX_OBJECT_DEPTH { return Err(LambdaError::ExpressionSizeLimit) }
+		
+		let limit = if let Some(n) = self.budget.max_steps && self.steps >= n { Some(Limit::Steps(n)) }
+		else if let Some(n) = self.budget.max_size && expr.object.size() > n { Some(Limit::Size(n)) }
+		else if let Some(ms) = self.budget.max_time && milliseconds() - self.started > ms { Some(Limit::Time(ms)) }
+		else { None };
+		
+		if let Some(limit) = limit { return Err(LambdaError::LimitReached(limit, printer::format(expr))) }
+		
+		let form = expr.de_bruijn();
+		let fingerprint = form.fingerprint();
+		
+		if let Some((previous_form, previous)) = self.repeated.get(&fingerprint) && *previous_form == form {
+			return Err(LambdaError::Divergent(self.steps - previous))
+		}
+		if self.visited.insert(fingerprint, self.steps).is_some() {
+			self.repeated.insert(fingerprint, (form, self.steps));
+		}
+		
+		self.steps += 1;
+		
+		Ok(())
+	}
+}
+
+#[cfg(target_arch = "wasm32")]
+fn milliseconds() -> f64 { js_sys::Date::now() }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn milliseconds() -> f64 {
+	use std::time::{SystemTime, UNIX_EPOCH};
+	SystemTime::now().duration_since(UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64() * 1000.0)
+}
+
+// -- Evaluation -- //
+
+// DOCS Running out of room for the expression counts as exhausting the budget, even though
+// 		the depth limit can't be changed.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+	NormalForm,
+	Error,
+	BudgetExhausted,
+	Diverged,
+}
+
+impl Status {
+	pub(crate) fn of(e: &LambdaError) -> Self {
+		match e {
+			LambdaError::LimitReached(..) | LambdaError::ExpressionSizeLimit => Self::BudgetExhausted,
+			LambdaError::Divergent(..) => Self::Diverged,
+			_ => Self::Error,
+		}
+	}
+}
+
+// DOCS The outcome of reducing an expression. `output` holds the normal form, or the error
+// 		message if there isn't one, so it's always the same text that `simplify` returns.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvalResult {
+	pub status: Status,
+	pub output: String,
+	pub error_kind: Option<ErrorKind>,
+	pub span: Option<Span>,
+	pub steps: usize,
+	pub size: usize,
+}
+
+impl EvalResult {
+	pub(crate) fn new(result: Result<String>, steps: usize, size: usize) -> Self {
+		match result {
+			Ok(output) => Self { status: Status::NormalForm, output, error_kind: None, span: None, steps, size },
+			Err(e) => Self {
+				status: Status::of(&e),
+				output: e.to_string(),
+				error_kind: Some(e.kind()),
+				span: e.span(),
+				steps,
+				size,
+			}
+		}
+	}
+	
+	// DOCS Formats the output like `LambdaError::underline`, when there's an error with a span.
+	pub fn underline(&self, source: &str) -> String {
+		match self.span {
+			Some(span) => errors::underline(&self.output, span, source),
+			None => self.output.clone(),
+		}
+	}
+}
+
+// -- Trace -- //
+
+// DOCS `failure` is only set on a step that couldn't be taken, which is always the last step.
+// 		Its `term` is then the error message, and its `redex` is empty.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceStep {
+	pub redex: String,
+	pub term: String,
+	pub failure: Option<Status>,
+}
+
+impl TraceStep {
+	pub(crate) fn failed(e: &LambdaError) -> Self {
+		Self { redex: String::new(), term: e.to_string(), failure: Some(Status::of(e)) }
+	}
+}
+
+// DOCS Yields every beta reduction step of an expression, in the order picked by the strategy.
+#[derive(Clone, Debug)]
+pub struct Trace {
+	expr: Expr,
+	strategy: Strategy,
+	eta: Eta,
+	style: Style,
+	index: KeywordIndex,
+	meter: Meter,
+	finished: bool,
+}
+
+impl Trace {
+	pub fn new(expression: String, parser: &Parser, strategy: Strategy, budget: &Budget) -> Result<Self> {
+		let tokens = parser.tokenize(expression)?;
+		let expr = builder::build(tokens)?;
+		
+		Ok(Self {
+			expr,
+			strategy,
+			eta: parser.eta(),
+			style: parser.style(),
+			index: parser.keyword_index().clone(),
+			meter: budget.start(),
+			finished: false,
+		})
+	}
+	
+	pub fn term(&self) -> String { printer::format_with(&self.expr, &self.style, &self.index) }
+}
+
+impl Iterator for Trace {
+	type Item = Result<TraceStep>;
+	
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished { return None }
+		
+		let Some(found) = self.expr.find_redex(self.strategy, self.eta) else {
+			self.finished = true;
+			if self.eta != Eta::Expand { return None }
+			
+			// The eta expansion is shown as a final step that rewrites the entire normal form.
+			let redex = printer::format_with(&self.expr, &self.style, &self.index);
+			self.expr.expand_eta();
+			
+			return Some(Ok(TraceStep { redex, term: printer::format_with(&self.expr, &self.style, &self.index), failure: None }))
+		};
+		
+		let redex = printer::format_redex(&self.expr, &found, &self.style, &self.index);
+		if let Err(e) = self.meter.step(&self.expr).and_then(|_| self.expr.contract(&found)) {
+			self.finished = true;
+			return Some(Err(e))
+		}
+		
+		Some(Ok(TraceStep { redex, term: printer::format_with(&self.expr, &self.style, &self.index), failure: None }))
+	}
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{evaluate, evaluate_within, trace};
+	
+	const STRATEGIES: [Strategy; 4] = [Strategy::Normal, Strategy::Applicative, Strategy::CallByName, Strategy::CallByValue];
+	
+	fn results(expression: &str) -> Vec<(Status, String)> {
+		let parser = Parser::new();
+		
+		STRATEGIES
+			.iter()
+			.map(|strategy| evaluate(expression.to_string(), &parser, *strategy))
+			.map(|result| (result.status, result.output))
+			.collect()
+	}
+	
+	fn normal(output: &str) -> (Status, String) { (Status::NormalForm, output.to_string()) }
+	
+	#[test]
+	fn unused_divergent_argument() {
+		let diverged = (Status::Diverged, LambdaError::Divergent(1).to_string());
+		
+		assert_eq!(results("(λx.y) ((λx.x x) (λx.x x))"), [normal("y"), diverged.clone(), normal("y"), diverged]);
+	}
+	
+	#[test]
+	fn divergence_blames_the_strategy() {
+		let output = evaluate("(λx.y) ((λx.x x) (λx.x x))".to_string(), &Parser::new(), Strategy::Applicative).output;
+		
+		assert_eq!(output, "divergence error: the strategy loops without reaching a normal form (cycle of length 1 detected)");
+	}
+	
+	#[test]
+	fn step_and_size_limits_keep_the_partial_term() {
+		let parser = Parser::new();
+		let expression = "(λx.x x x) (λx.x x x)";
+		
+		let mut steps = Budget::new();
+		steps.set_max_steps(Some(2));
+		let mut size = Budget::new();
+		size.set_max_size(Some(20));
+		
+		for (budget, limit, copies) in [(steps, Limit::Steps(2), 4), (size, Limit::Size(20), 6)] {
+			let partial = vec!["(λx.x x x)"; copies].join(" ");
+			let mut expr = builder::build(parser.tokenize(expression.to_string()).unwrap()).unwrap();
+			let error = expr.reduce(Strategy::Normal, Eta::default(), &budget).unwrap_err();
+			assert!(matches!(error, LambdaError::LimitReached(l, ref p) if l == limit && *p == partial), "{error}");
+			
+			let result = evaluate_within(expression.to_string(), &parser, Strategy::Normal, &budget);
+			assert_eq!((result.status, result.error_kind), (Status::BudgetExhausted, Some(ErrorKind::Budget)));
+			assert_eq!(result.output, error.to_string());
+		}
+	}
+	
+	#[test]
+	fn depth_limit_exhausts_the_budget() {
+		let result = evaluate("(λx.x x) (λx.λy.x x y)".to_string(), &Parser::new(), Strategy::Applicative);
+		
+		assert_eq!(result.status, Status::BudgetExhausted);
+		assert_eq!(result.error_kind, Some(ErrorKind::Budget));
+		assert_eq!(result.output, "budget error: reached the depth limit of 512 nested functions");
+	}
+	
+	#[test]
+	fn applied_numeral_is_not_a_cycle() {
+		let mut style = Style::new();
+		style.set_minimal_parens(true);
+		
+		let mut parser = Parser::new();
+		parser.set_style(&style);
+		
+		let result = evaluate("v (λw.1 w) ((λr.r) a)".to_string(), &parser, Strategy::Normal);
+		assert_eq!((result.status, result.output.as_str()), (Status::NormalForm, "v (λw.λx.w x) a"));
+		
+		// `λf.(λx.f (f x))` is the numeral 2, so applying it has to make progress like `2` does.
+		let result = evaluate("(λf.(λx.f (f x))) (a a) b".to_string(), &parser, Strategy::Normal);
+		assert_eq!((result.status, result.output.as_str()), (Status::NormalForm, "a a (a a b)"));
+	}
+	
+	#[test]
+	fn numeral_shaped_terms_are_not_cycles() {
+		let parser = Parser::new();
+		
+		for strategy in [Strategy::Normal, Strategy::Applicative] {
+			let result = evaluate("(λn.λf.λx.f (n f x)) ((λn.λf.λx.f (n f x)) 0)".to_string(), &parser, strategy);
+			assert_eq!((result.status, result.output.as_str()), (Status::NormalForm, "λf.λx.f (f x)"));
+		}
+	}
+	
+	#[test]
+	fn fingerprint_collision_is_not_a_cycle() {
+		let parser = Parser::new();
+		let build = |expression: &str| builder::build(parser.tokenize(expression.to_string()).unwrap()).unwrap();
+		let (a, b) = (build("a b"), build("b a"));
+		
+		let mut meter = Budget::new().start();
+		meter.step(&a).unwrap();
+		
+		// `b a` is given the fingerprint of an earlier expression that it isn't equal to.
+		meter.visited.insert(b.de_bruijn().fingerprint(), 0);
+		
+		assert!(meter.step(&b).is_ok());
+		assert!(matches!(meter.step(&b), Err(LambdaError::Divergent(1))));
+	}
+	
+	#[test]
+	fn redex_under_function() {
+		assert_eq!(results("λx.(λy.y) x"), [normal("λx.x"), normal("λx.x"), normal("λx.(λy.y) x"), normal("λx.(λy.y) x")]);
+	}
+	
+	#[test]
+	fn redex_in_argument() {
+		assert_eq!(results("(λx.x) ((λy.y) z)"), [normal("z"), normal("z"), normal("z"), normal("z")]);
+		
+		let parser = Parser::new();
+		let first_redexes = STRATEGIES
+			.iter()
+			.map(|strategy| trace("(λx.x) ((λy.y) z)".to_string(), &parser, *strategy).remove(0).redex)
+			.collect::<Vec<String>>();
+		
+		assert_eq!(first_redexes, ["(λx.x) ((λy.y) z)", "(λy.y) z", "(λx.x) ((λy.y) z)", "(λy.y) z"]);
+	}
+	
+	#[test]
+	fn trace_marks_failure() {
+		let steps = trace("(λx.x x) (λx.x x)".to_string(), &Parser::new(), Strategy::Normal);
+		
+		// The cycle is only reported once the repeated form comes back a second time.
+		let step = TraceStep { redex: "(λx.x x) (λx.x x)".to_string(), term: "(λx.x x) (λx.x x)".to_string(), failure: None };
+		assert_eq!(steps.len(), 3);
+		assert_eq!(steps[..2], [step.clone(), step]);
+		assert_eq!(steps[2].failure, Some(Status::Diverged));
+		
+		let steps = trace("λx.".to_string(), &Parser::new(), Strategy::Normal);
+		assert!(steps.iter().all(|step| step.failure.is_none()));
+		
+		let steps = trace(")".to_string(), &Parser::new(), Strategy::Normal);
+		assert_eq!(steps.iter().map(|step| step.failure).collect::<Vec<_>>(), [Some(Status::Error)]);
+	}
+}
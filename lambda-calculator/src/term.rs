@@ -0,0 +1,285 @@
+// -- Imports -- //
+
+use crate::builder;
+use crate::delta::Primitive;
+use crate::errors::{LambdaError, Result, Span};
+use crate::objects::Expr;
+use crate::parser::{Parser, Token, RESERVED_WORDS, VARIABLE_VALIDATOR};
+use crate::printer;
+use crate::reduction::{Budget, Eta, Strategy};
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// -- Typing -- //
+
+// DOCS A lambda term that can be built and taken apart outside of the crate. Keywords are
+// 		already replaced by their definitions, and Church numerals are unfolded into functions.
+// 		`Int` and `Prim` only come from parsers with native arithmetic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Term {
+	Var(String),
+	Lam(String, Box<Term>),
+	App(Box<Term>, Box<Term>),
+	Int(i64),
+	Prim(Primitive),
+}
+
+// -- Constructors -- //
+
+// DOCS Names aren't checked when a term is built, but a term with a name that the parser
+// 		wouldn't accept as a variable, like `1` or `let`, can't be normalized.
+impl Term {
+	pub fn var(name: impl Into<String>) -> Self { Self::Var(name.into()) }
+	pub fn lam(name: impl Into<String>, body: Term) -> Self { Self::Lam(name.into(), Box::new(body)) }
+	pub fn app(function: Term, argument: Term) -> Self { Self::App(Box::new(function), Box::new(argument)) }
+	
+	// DOCS Reads the expression with the parser's keywords and arithmetic, without reducing it.
+	pub fn parse(expression: &str, parser: &Parser) -> Result<Self> {
+		let expr = builder::build(parser.tokenize(expression.to_string())?)?;
+		Ok(printer::to_term(&expr))
+	}
+}
+
+impl FromStr for Term {
+	type Err = LambdaError;
+	
+	fn from_str(s: &str) -> Result<Self> { Self::parse(s, &Parser::new()) }
+}
+
+// -- Reduction -- //
+
+impl Term {
+	pub fn normalize(&self) -> Result<Self> {
+		self.normalize_with(Strategy::default(), Eta::default(), &Budget::default())
+	}
+	
+	pub fn normalize_with(&self, strategy: Strategy, eta: Eta, budget: &Budget) -> Result<Self> {
+		let mut expr = self.to_expr()?;
+		expr.reduce(strategy, eta, budget)?;
+		
+		Ok(printer::to_term(&expr))
+	}
+	
+	pub fn free_variables(&self) -> BTreeSet<String> {
+		let mut free = BTreeSet::new();
+		self.collect_free(&mut Vec::new(), &mut free);
+		
+		free
+	}
+	
+	fn collect_free<'a>(&'a self, bound: &mut Vec<&'a str>, free: &mut BTreeSet<String>) {
+		match self {
+			Self::Var(name) => if !bound.contains(&name.as_str()) { free.insert(name.clone()); },
+			Self::Lam(name, body) => {
+				bound.push(name);
+				body.collect_free(bound, free);
+				bound.pop();
+			}
+			
+			Self::App(function, argument) => {
+				function.collect_free(bound, free);
+				argument.collect_free(bound, free);
+			}
+			
+			Self::Int(_) | Self::Prim(_) => (),
+		}
+	}
+	
+	// DOCS The builder can't reuse the name of a free variable or of an enclosing function for
+	// 		a function variable, so those variables are renamed with a suffix first.
+	fn to_expr(&self) -> Result<Expr> {
+		self.check_names()?;
+		self.build()
+	}
+	
+	fn build(&self) -> Result<Expr> {
+		let mut tokens = Tokens {
+			used: self.free_variables().into_iter().collect(),
+			scope: Vec::new(),
+			out: Vec::new(),
+		};
+		
+		if let Self::Lam(..) = self { tokens.push(self) }
+		else {
+			tokens.out.push((Token::Open, Span::default()));
+			tokens.push_spine(self);
+			tokens.out.push((Token::Close, Span::default()));
+		}
+		
+		builder::build(tokens.out)
+	}
+	
+	fn check_names(&self) -> Result<()> {
+		match self {
+			Self::Var(name) | Self::Lam(name, _) if !VARIABLE_VALIDATOR.is_match(name) || RESERVED_WORDS.contains(&name.as_str()) =>
+				Err(LambdaError::InvalidName(name.clone(), Span::default())),
+			
+			Self::Lam(_, body) => body.check_names(),
+			Self::App(function, argument) => {
+				function.check_names()?;
+				argument.check_names()
+			}
+			
+			Self::Var(_) | Self::Int(_) | Self::Prim(_) => Ok(()),
+		}
+	}
+}
+
+struct Tokens {
+	used: HashSet<String>,
+	scope: Vec<(String, String)>,
+	out: Vec<(Token, Span)>,
+}
+
+impl Tokens {
+	fn push(&mut self, term: &Term) {
+		match term {
+			Term::Var(name) => {
+				let name = self.scope.iter().rev().find(|(original, _)| original == name).map_or(name, |(_, renamed)| renamed);
+				self.out.push((Token::Var(name.clone()), Span::default()));
+			}
+			
+			Term::Int(value) => self.out.push((Token::Int(*value), Span::default())),
+			Term::Prim(op) => self.out.push((Token::Prim(*op), Span::default())),
+			
+			// `λx.λy.b` is one function with two variables, like it is when it's parsed.
+			Term::Lam(..) => {
+				let mut body = term;
+				let depth = self.scope.len();
+				self.out.push((Token::Open, Span::default()));
+				
+				while let Term::Lam(name, inner) = body {
+					let renamed = self.fresh(name);
+					self.out.push((Token::Func(renamed.clone()), Span::default()));
+					self.scope.push((name.clone(), renamed));
+					body = inner;
+				}
+				
+				self.push_spine(body);
+				self.out.push((Token::Close, Span::default()));
+				self.scope.truncate(depth);
+			}
+			
+			Term::App(..) => {
+				self.out.push((Token::Open, Span::default()));
+				self.push_spine(term);
+				self.out.push((Token::Close, Span::default()));
+			}
+		}
+	}
+	
+	// DOCS Applications are pushed without parentheses around the function, so that `f a b`
+	// 		is a single closure.
+	fn push_spine(&mut self, term: &Term) {
+		match term {
+			Term::App(function, argument) => {
+				self.push_spine(function);
+				self.push(argument);
+			}
+			
+			term => self.push(term),
+		}
+	}
+	
+	fn fresh(&mut self, name: &str) -> String {
+		let renamed = (0..)
+			.map(|suffix| if suffix == 0 { name.to_string() } else { format!("{name}_{suffix}") })
+			.find(|n| !self.used.contains(n))
+			.unwrap_or_default();
+		
+		self.used.insert(renamed.clone());
+		renamed
+	}
+}
+
+// -- Printing -- //
+
+// DOCS Prints terms the same way as `simplify`, through the same printer. A term with a name
+// 		that the parser wouldn't accept can't be printed, so formatting it fails. `fix` can't be
+// 		read back either, so it's printed as `Z`, which unfolds the same way once it's applied.
+impl Display for Term {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.check_names().map_err(|_| std::fmt::Error)?;
+		
+		let expr = self.unfold_fix().build().map_err(|_| std::fmt::Error)?;
+		write!(f, "{}", printer::format(&expr))
+	}
+}
+
+impl Term {
+	fn unfold_fix(&self) -> Self {
+		match self {
+			Self::Prim(Primitive::Fix) => {
+				let half = Self::lam("x", Self::app(Self::var("f"), Self::lam("v", Self::app(Self::app(Self::var("x"), Self::var("x")), Self::var("v")))));
+				Self::lam("f", Self::app(half.clone(), half))
+			}
+			
+			Self::Lam(name, body) => Self::lam(name.clone(), body.unfold_fix()),
+			Self::App(function, argument) => Self::app(function.unfold_fix(), argument.unfold_fix()),
+			Self::Var(_) | Self::Int(_) | Self::Prim(_) => self.clone(),
+		}
+	}
+}
+
+// -- Tests -- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::equivalence::Equivalence;
+	
+	#[test]
+	fn display_matches_the_printer() {
+		let identity = Term::lam("x", Term::var("x"));
+		
+		assert_eq!(identity.to_string(), "λx.x");
+		assert_eq!(Term::app(Term::var("f"), Term::app(Term::var("g"), Term::var("a"))).to_string(), "f (g a)");
+		assert_eq!(Term::app(identity.clone(), Term::var("y")).to_string(), "(λx.x) y");
+		assert_eq!(Term::lam("x", Term::lam("x", Term::var("x"))).to_string(), "λx.λx_1.x_1");
+		
+		let parser = Parser::new();
+		
+		for source in ["λf.λx.f (f x)", "a (λx.x) b", "λx.x (λy.y x) x"] {
+			assert_eq!(Term::parse(source, &parser).unwrap().to_string(), source);
+		}
+	}
+	
+	#[test]
+	fn invalid_names_are_rejected() {
+		let identity = Term::lam("x", Term::var("x"));
+		
+		for name in ["1", "let", "x y", ""] {
+			let error = Term::app(identity.clone(), Term::var(name)).normalize().unwrap_err();
+			assert!(matches!(error, LambdaError::InvalidName(ref n, _) if n == name), "{name:?}: {error}");
+			
+			let error = Term::lam(name, Term::var("y")).normalize().unwrap_err();
+			assert!(matches!(error, LambdaError::InvalidName(ref n, _) if n == name), "{name:?}: {error}");
+		}
+		
+		assert_eq!(Term::app(identity, Term::var("y_1")).normalize().unwrap(), Term::var("y_1"));
+	}
+	
+	#[test]
+	fn unreadable_names_are_not_printed() {
+		use std::fmt::Write;
+		
+		for term in [Term::var("$a$b"), Term::lam("λ", Term::var("λ")), Term::lam("x", Term::var("let"))] {
+			assert!(write!(String::new(), "{term}").is_err(), "{term:?}");
+		}
+	}
+	
+	#[test]
+	fn recursive_keyword_reads_back() {
+		let mut prelude = Parser::with_prelude();
+		prelude.define("fact", "λn.is_null n 1 (mul n (fact (pred n)))").unwrap();
+		
+		let shown = Term::parse("fact", &prelude).unwrap().to_string();
+		assert!(!shown.contains("fix"), "{shown}");
+		
+		let plain = Parser::new();
+		assert_eq!(Term::parse(&shown, &plain).unwrap().to_string(), shown);
+		assert_eq!(crate::equivalent(format!("({shown}) 3"), "6".to_string(), &plain).unwrap(), Equivalence::BetaEquivalent);
+	}
+}
@@ -0,0 +1,123 @@
+// -- Imports -- //
+
+use lambda_calculator::{evaluate, Parser, Status, Strategy, Style, Term};
+
+// -- Terms -- //
+
+// DOCS A linear congruential generator, so that the same terms are checked on every run.
+struct Terms {
+	seed: u64,
+}
+
+impl Terms {
+	fn next(&mut self, below: u64) -> u64 {
+		self.seed = self.seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		(self.seed >> 33) % below
+	}
+	
+	fn term(&mut self, depth: usize, bound: &mut Vec<&'static str>) -> Term {
+		match self.next(10) {
+			_ if depth == 0 => self.variable(bound),
+			0..3 => self.variable(bound),
+			3..6 => {
+				let name = ["x", "y", "z", "w"][self.next(4) as usize];
+				bound.push(name);
+				let body = self.term(depth - 1, bound);
+				bound.pop();
+				
+				Term::lam(name, body)
+			}
+			
+			_ => Term::app(self.term(depth - 1, bound), self.term(depth - 1, bound)),
+		}
+	}
+	
+	fn variable(&mut self, bound: &[&'static str]) -> Term {
+		match self.next(2) {
+			0 if !bound.is_empty() => Term::var(bound[self.next(bound.len() as u64) as usize]),
+			_ => Term::var(["a", "b", "c"][self.next(3) as usize]),
+		}
+	}
+}
+
+fn parser(minimal_parens: bool, width: Option<usize>) -> Parser {
+	let mut style = Style::new();
+	style.set_minimal_parens(minimal_parens);
+	style.set_width(width);
+	
+	let mut parser = Parser::new();
+	parser.set_style(&style);
+	
+	parser
+}
+
+// -- Tests -- //
+
+// DOCS Normal forms printed with minimal parentheses or across lines have to read back as
+// 		the same term.
+#[test]
+fn printed_normal_forms_read_back() {
+	let plain = parser(false, None);
+	let minimal = parser(true, None);
+	let pretty = parser(true, Some(20));
+	
+	let mut terms = Terms { seed: 7 };
+	let mut checked = 0;
+	
+	for _ in 0..1000 {
+		let source = terms.term(7, &mut Vec::new()).to_string();
+		let normal = evaluate(source.clone(), &plain, Strategy::default());
+		if normal.status != Status::NormalForm { continue }
+		
+		let short = evaluate(source.clone(), &minimal, Strategy::default()).output;
+		assert!(short.len() <= normal.output.len(), "{source}: {short} / {}", normal.output);
+		assert_eq!(evaluate(short.clone(), &minimal, Strategy::default()).output, short, "{source}");
+		assert_eq!(evaluate(normal.output.clone(), &minimal, Strategy::default()).output, short, "{source}");
+		
+		let broken = evaluate(source.clone(), &pretty, Strategy::default()).output;
+		assert_eq!(evaluate(broken.clone(), &minimal, Strategy::default()).output, short, "{source}: {broken}");
+		
+		checked += 1;
+	}
+	
+	assert!(checked > 500, "only {checked} terms had a normal form");
+}
+
+// DOCS Functions printed with `\` and with compressed binders have to read back as the same
+// 		term, and so does `->` in place of the point.
+#[test]
+fn ascii_and_compressed_binders_read_back() {
+	let plain = parser(false, None);
+	
+	for (ascii, compress_binders) in [(true, false), (false, true), (true, true)] {
+		let mut style = Style::new();
+		style.set_ascii(ascii);
+		style.set_compress_binders(compress_binders);
+		
+		let mut styled = Parser::new();
+		styled.set_style(&style);
+		
+		let mut terms = Terms { seed: 11 };
+		let mut changed = 0;
+		
+		for _ in 0..500 {
+			let source = terms.term(7, &mut Vec::new()).to_string();
+			let normal = evaluate(source.clone(), &plain, Strategy::default());
+			if normal.status != Status::NormalForm { continue }
+			
+			let printed = evaluate(source.clone(), &styled, Strategy::default()).output;
+			assert!(!ascii || !printed.contains('λ'), "{source}: {printed}");
+			if printed != normal.output { changed += 1 }
+			
+			assert_eq!(evaluate(printed.clone(), &plain, Strategy::default()).output, normal.output, "{source}: {printed}");
+			
+			let arrows = printed.replace('.', " -> ");
+			assert_eq!(evaluate(arrows.clone(), &plain, Strategy::default()).output, normal.output, "{source}: {arrows}");
+		}
+		
+		assert!(changed > 100, "only {changed} normal forms were printed differently");
+	}
+	
+	assert_eq!(Term::parse("\\x y -> y x", &plain).unwrap(), Term::parse("λx.λy.y x", &plain).unwrap());
+}
+
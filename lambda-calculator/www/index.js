@@ -22,12 +22,16 @@ const add_shorthand_button = document.getElementById("button-add-shorthand");
 /** @type {HTMLButtonElement} */
 const lambda_icon_button = document.getElementById("lambda-icon");
 
-const parser = Parser.new();
+const parser = Parser.with_prelude();
+
+/** Long results are broken across lines at this many characters. */
+const OUTPUT_WIDTH = 60;
 
 // -- Functions -- //
 
 const update_output_text = () => {
 	output_box.value = simplify(input_box.value, parser);
+	output_box.rows = output_box.value.split("\n").length;
 }
 
 const add_shorthand = () => {
@@ -47,57 +51,23 @@ const add_shorthand = () => {
 
 // -- Runtime Init -- //
 
+const style = parser.style();
+style.minimal_parens = true;
+style.width = OUTPUT_WIDTH;
+parser.set_style(style);
+
 simplify_button.addEventListener("click", update_output_text);
 add_shorthand_button.addEventListener("click", add_shorthand);
 lambda_icon_button.addEventListener("click", (_) => {
 	navigator.clipboard.writeText("λ").then(() => {}).catch();
 })
 
-parser.create_keyword("true", "λx.λy.x");
-parser.create_keyword("false", "λx.λy.y");
-parser.create_keyword("not", "λp.p false true");
-parser.create_keyword("and", "λp.λq.p q p");
-parser.create_keyword("or", "λp.λq.p p q");
-
-add_table_row("true", "λx.λy.x", parser);
-add_table_row("false", "λx.λy.y", parser);
-add_table_row("not", "λp.p false true", parser);
-add_table_row("and", "λp.λq.p q p", parser);
-add_table_row("or", "λp.λq.p p q", parser);
-
-parser.create_keyword("null", "λf.λx.x");
-parser.create_keyword("succ", "λn.λf.λx.f (n f x)");
-parser.create_keyword("pred", "λn.λf.λx.n (λg.λh.h (g f)) (λu.x) (λu.u)");
-
-add_table_row("null", "λf.λx.x", parser);
-add_table_row("succ", "λn.λf.λx.f (n f x)", parser);
-add_table_row("pred", "λn.λf.λx.n (λg.λh.h (g f)) (λu.x) (λu.u)", parser);
-
-parser.create_keyword("add", "λm.λn.m succ n");
-parser.create_keyword("sub", "λm.λn.n pred m");
-parser.create_keyword("mul", "λm.λn.m (add n) null");
-parser.create_keyword("pow", "λb.λe.e b");
-
-add_table_row("add", "λm.λn.m succ n", parser);
-add_table_row("sub", "λm.λn.n pred m", parser);
-add_table_row("mul", "λm.λn.m (add n) null", parser);
-add_table_row("pow", "λb.λe.e b", parser);
-
-parser.create_keyword("is_null", "λn.n (λx.false) true");
-parser.create_keyword("is_ge", "λm.λn.is_null (sub n m)");
-parser.create_keyword("is_le", "λm.λn.is_null (sub m n)");
-parser.create_keyword("is_eq", "λm.λn.and (is_ge m n) (is_ge n m)");
-
-add_table_row("is_null", "λn.n (λx.false) true", parser);
-add_table_row("is_ge", "λm.λn.is_null (sub n m)", parser);
-add_table_row("is_le", "λm.λn.is_null (sub m n)", parser);
-add_table_row("is_eq", "λm.λn.and (is_ge m n) (is_le m n)", parser);
-
 parser.create_keyword("++", "succ");
 parser.create_keyword("--", "pred");
 
-add_table_row("++", "succ", parser);
-add_table_row("--", "pred", parser);
+for (const name of parser.keyword_names()) {
+	add_table_row(name, parser.keyword_expression(name), parser);
+}
 
 input_box.value = "";
 output_box.value = "";
@@ -16,7 +16,7 @@
   \***********************************/
 /***/ ((__webpack_module__, __webpack_exports__, __webpack_require__) => {
 
-eval("{__webpack_require__.a(__webpack_module__, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Parser: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Parser),\n/* harmony export */   __wbg_error_7534b8e9a36f1ab4: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_error_7534b8e9a36f1ab4),\n/* harmony export */   __wbg_new_8a6f238a6ece86ea: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_new_8a6f238a6ece86ea),\n/* harmony export */   __wbg_set_wasm: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_set_wasm),\n/* harmony export */   __wbg_stack_0ed75d68575b0f3c: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_stack_0ed75d68575b0f3c),\n/* harmony export */   __wbindgen_init_externref_table: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbindgen_init_externref_table),\n/* harmony export */   __wbindgen_throw: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbindgen_throw),\n/* harmony export */   simplify: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.simplify)\n/* harmony export */ });\n/* harmony import */ var _lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./lambda_calculator_bg.wasm */ \"../pkg/lambda_calculator_bg.wasm\");\n/* harmony import */ var _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./lambda_calculator_bg.js */ \"../pkg/lambda_calculator_bg.js\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__]);\n_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__ = (__webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__)[0];\n\n\n\n(0,_lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_set_wasm)(_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__);\n_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__.__wbindgen_start();\n\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator.js?\n}");
+eval("{__webpack_require__.a(__webpack_module__, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Arithmetic: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Arithmetic),\n/* harmony export */   Budget: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Budget),\n/* harmony export */   Equivalence: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Equivalence),\n/* harmony export */   ErrorKind: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.ErrorKind),\n/* harmony export */   ErrorReport: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.ErrorReport),\n/* harmony export */   Eta: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Eta),\n/* harmony export */   EvalResult: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.EvalResult),\n/* harmony export */   LineResult: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.LineResult),\n/* harmony export */   Parser: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Parser),\n/* harmony export */   Span: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Span),\n/* harmony export */   Status: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Status),\n/* harmony export */   Strategy: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Strategy),\n/* harmony export */   Style: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.Style),\n/* harmony export */   TestFailure: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.TestFailure),\n/* harmony export */   TestReport: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.TestReport),\n/* harmony export */   TraceStep: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.TraceStep),\n/* harmony export */   equivalent: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.equivalent),\n/* harmony export */   evaluate: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.evaluate),\n/* harmony export */   evaluate_within: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.evaluate_within),\n/* harmony export */   simplify: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.simplify),\n/* harmony export */   simplify_with: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.simplify_with),\n/* harmony export */   simplify_within: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.simplify_within),\n/* harmony export */   trace: () => (/* reexport safe */ _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.trace)\n/* harmony export */ });\n/* harmony import */ var _lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! ./lambda_calculator_bg.wasm */ \"../pkg/lambda_calculator_bg.wasm\");\n/* harmony import */ var _lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__ = __webpack_require__(/*! ./lambda_calculator_bg.js */ \"../pkg/lambda_calculator_bg.js\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__]);\n_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__ = (__webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__)[0];\n/* @ts-self-types=\"./lambda_calculator.d.ts\" */\n\n\n\n(0,_lambda_calculator_bg_js__WEBPACK_IMPORTED_MODULE_1__.__wbg_set_wasm)(_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__);\n_lambda_calculator_bg_wasm__WEBPACK_IMPORTED_MODULE_0__.__wbindgen_start();\n\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator.js?\n}");
 
 /***/ }),
 
@@ -26,7 +26,7 @@ eval("{__webpack_require__.a(__webpack_module__, async (__webpack_handle_async_d
   \**************************************/
 /***/ ((__unused_webpack___webpack_module__, __webpack_exports__, __webpack_require__) => {
 
-eval("{__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Parser: () => (/* binding */ Parser),\n/* harmony export */   __wbg_error_7534b8e9a36f1ab4: () => (/* binding */ __wbg_error_7534b8e9a36f1ab4),\n/* harmony export */   __wbg_new_8a6f238a6ece86ea: () => (/* binding */ __wbg_new_8a6f238a6ece86ea),\n/* harmony export */   __wbg_set_wasm: () => (/* binding */ __wbg_set_wasm),\n/* harmony export */   __wbg_stack_0ed75d68575b0f3c: () => (/* binding */ __wbg_stack_0ed75d68575b0f3c),\n/* harmony export */   __wbindgen_init_externref_table: () => (/* binding */ __wbindgen_init_externref_table),\n/* harmony export */   __wbindgen_throw: () => (/* binding */ __wbindgen_throw),\n/* harmony export */   simplify: () => (/* binding */ simplify)\n/* harmony export */ });\nlet wasm;\nfunction __wbg_set_wasm(val) {\n    wasm = val;\n}\n\n\nconst lTextDecoder = typeof TextDecoder === 'undefined' ? (0, module.require)('util').TextDecoder : TextDecoder;\n\nlet cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });\n\ncachedTextDecoder.decode();\n\nlet cachedUint8ArrayMemory0 = null;\n\nfunction getUint8ArrayMemory0() {\n    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {\n        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);\n    }\n    return cachedUint8ArrayMemory0;\n}\n\nfunction getStringFromWasm0(ptr, len) {\n    ptr = ptr >>> 0;\n    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));\n}\n\nlet WASM_VECTOR_LEN = 0;\n\nconst lTextEncoder = typeof TextEncoder === 'undefined' ? (0, module.require)('util').TextEncoder : TextEncoder;\n\nlet cachedTextEncoder = new lTextEncoder('utf-8');\n\nconst encodeString = (typeof cachedTextEncoder.encodeInto === 'function'\n    ? function (arg, view) {\n    return cachedTextEncoder.encodeInto(arg, view);\n}\n    : function (arg, view) {\n    const buf = cachedTextEncoder.encode(arg);\n    view.set(buf);\n    return {\n        read: arg.length,\n        written: buf.length\n    };\n});\n\nfunction passStringToWasm0(arg, malloc, realloc) {\n\n    if (realloc === undefined) {\n        const buf = cachedTextEncoder.encode(arg);\n        const ptr = malloc(buf.length, 1) >>> 0;\n        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);\n        WASM_VECTOR_LEN = buf.length;\n        return ptr;\n    }\n\n    let len = arg.length;\n    let ptr = malloc(len, 1) >>> 0;\n\n    const mem = getUint8ArrayMemory0();\n\n    let offset = 0;\n\n    for (; offset < len; offset++) {\n        const code = arg.charCodeAt(offset);\n        if (code > 0x7F) break;\n        mem[ptr + offset] = code;\n    }\n\n    if (offset !== len) {\n        if (offset !== 0) {\n            arg = arg.slice(offset);\n        }\n        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;\n        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);\n        const ret = encodeString(arg, view);\n\n        offset += ret.written;\n        ptr = realloc(ptr, len, offset, 1) >>> 0;\n    }\n\n    WASM_VECTOR_LEN = offset;\n    return ptr;\n}\n\nlet cachedDataViewMemory0 = null;\n\nfunction getDataViewMemory0() {\n    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {\n        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);\n    }\n    return cachedDataViewMemory0;\n}\n\nfunction _assertClass(instance, klass) {\n    if (!(instance instanceof klass)) {\n        throw new Error(`expected instance of ${klass.name}`);\n    }\n}\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @returns {string}\n */\nfunction simplify(expression, parser) {\n    let deferred2_0;\n    let deferred2_1;\n    try {\n        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        _assertClass(parser, Parser);\n        const ret = wasm.simplify(ptr0, len0, parser.__wbg_ptr);\n        deferred2_0 = ret[0];\n        deferred2_1 = ret[1];\n        return getStringFromWasm0(ret[0], ret[1]);\n    } finally {\n        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);\n    }\n}\n\nconst ParserFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_parser_free(ptr >>> 0, 1));\n\nclass Parser {\n\n    static __wrap(ptr) {\n        ptr = ptr >>> 0;\n        const obj = Object.create(Parser.prototype);\n        obj.__wbg_ptr = ptr;\n        ParserFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        ParserFinalization.unregister(this);\n        return ptr;\n    }\n\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_parser_free(ptr, 0);\n    }\n    /**\n     * @returns {Parser}\n     */\n    static new() {\n        const ret = wasm.parser_new();\n        return Parser.__wrap(ret);\n    }\n    /**\n     * @param {string} name\n     * @param {string} expression\n     * @returns {string}\n     */\n    create_keyword(name, expression) {\n        let deferred3_0;\n        let deferred3_1;\n        try {\n            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len0 = WASM_VECTOR_LEN;\n            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len1 = WASM_VECTOR_LEN;\n            const ret = wasm.parser_create_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);\n            deferred3_0 = ret[0];\n            deferred3_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);\n        }\n    }\n    /**\n     * @param {string} name\n     */\n    remove_keyword(name) {\n        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.parser_remove_keyword(this.__wbg_ptr, ptr0, len0);\n    }\n}\n\nfunction __wbg_error_7534b8e9a36f1ab4(arg0, arg1) {\n    let deferred0_0;\n    let deferred0_1;\n    try {\n        deferred0_0 = arg0;\n        deferred0_1 = arg1;\n        console.error(getStringFromWasm0(arg0, arg1));\n    } finally {\n        wasm.__wbindgen_free(deferred0_0, deferred0_1, 1);\n    }\n};\n\nfunction __wbg_new_8a6f238a6ece86ea() {\n    const ret = new Error();\n    return ret;\n};\n\nfunction __wbg_stack_0ed75d68575b0f3c(arg0, arg1) {\n    const ret = arg1.stack;\n    const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len1 = WASM_VECTOR_LEN;\n    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);\n    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);\n};\n\nfunction __wbindgen_init_externref_table() {\n    const table = wasm.__wbindgen_export_3;\n    const offset = table.grow(4);\n    table.set(0, undefined);\n    table.set(offset + 0, undefined);\n    table.set(offset + 1, null);\n    table.set(offset + 2, true);\n    table.set(offset + 3, false);\n    ;\n};\n\nfunction __wbindgen_throw(arg0, arg1) {\n    throw new Error(getStringFromWasm0(arg0, arg1));\n};\n\n\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator_bg.js?\n}");
+eval("{__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   Arithmetic: () => (/* binding */ Arithmetic),\n/* harmony export */   Budget: () => (/* binding */ Budget),\n/* harmony export */   Equivalence: () => (/* binding */ Equivalence),\n/* harmony export */   ErrorKind: () => (/* binding */ ErrorKind),\n/* harmony export */   ErrorReport: () => (/* binding */ ErrorReport),\n/* harmony export */   Eta: () => (/* binding */ Eta),\n/* harmony export */   EvalResult: () => (/* binding */ EvalResult),\n/* harmony export */   LineResult: () => (/* binding */ LineResult),\n/* harmony export */   Parser: () => (/* binding */ Parser),\n/* harmony export */   Span: () => (/* binding */ Span),\n/* harmony export */   Status: () => (/* binding */ Status),\n/* harmony export */   Strategy: () => (/* binding */ Strategy),\n/* harmony export */   Style: () => (/* binding */ Style),\n/* harmony export */   TestFailure: () => (/* binding */ TestFailure),\n/* harmony export */   TestReport: () => (/* binding */ TestReport),\n/* harmony export */   TraceStep: () => (/* binding */ TraceStep),\n/* harmony export */   __wbg___wbindgen_string_get_0380ccaa2f57f0d9: () => (/* binding */ __wbg___wbindgen_string_get_0380ccaa2f57f0d9),\n/* harmony export */   __wbg___wbindgen_throw_41e9ee4f547fc59a: () => (/* binding */ __wbg___wbindgen_throw_41e9ee4f547fc59a),\n/* harmony export */   __wbg_error_757e9472f8410341: () => (/* binding */ __wbg_error_757e9472f8410341),\n/* harmony export */   __wbg_errorreport_new: () => (/* binding */ __wbg_errorreport_new),\n/* harmony export */   __wbg_lineresult_new: () => (/* binding */ __wbg_lineresult_new),\n/* harmony export */   __wbg_new_227d7c05414eb861: () => (/* binding */ __wbg_new_227d7c05414eb861),\n/* harmony export */   __wbg_now_aa4ccb83129e9e55: () => (/* binding */ __wbg_now_aa4ccb83129e9e55),\n/* harmony export */   __wbg_set_wasm: () => (/* binding */ __wbg_set_wasm),\n/* harmony export */   __wbg_stack_3b0d974bbf31e44f: () => (/* binding */ __wbg_stack_3b0d974bbf31e44f),\n/* harmony export */   __wbg_testfailure_new: () => (/* binding */ __wbg_testfailure_new),\n/* harmony export */   __wbg_testfailure_unwrap: () => (/* binding */ __wbg_testfailure_unwrap),\n/* harmony export */   __wbg_tracestep_new: () => (/* binding */ __wbg_tracestep_new),\n/* harmony export */   __wbindgen_generic_0000000000000001: () => (/* binding */ __wbindgen_generic_0000000000000001),\n/* harmony export */   __wbindgen_init_externref_table: () => (/* binding */ __wbindgen_init_externref_table),\n/* harmony export */   equivalent: () => (/* binding */ equivalent),\n/* harmony export */   evaluate: () => (/* binding */ evaluate),\n/* harmony export */   evaluate_within: () => (/* binding */ evaluate_within),\n/* harmony export */   simplify: () => (/* binding */ simplify),\n/* harmony export */   simplify_with: () => (/* binding */ simplify_with),\n/* harmony export */   simplify_within: () => (/* binding */ simplify_within),\n/* harmony export */   trace: () => (/* binding */ trace)\n/* harmony export */ });\n/**\n * @enum {0 | 1}\n */\nconst Arithmetic = Object.freeze({\n    Church: 0, \"0\": \"Church\",\n    Native: 1, \"1\": \"Native\",\n});\n\nclass Budget {\n    static __wrap(ptr) {\n        const obj = Object.create(Budget.prototype);\n        obj.__wbg_ptr = ptr;\n        BudgetFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        BudgetFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_budget_free(ptr, 0);\n    }\n    /**\n     * @returns {number | undefined}\n     */\n    get max_size() {\n        const ret = wasm.budget_max_size(this.__wbg_ptr);\n        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;\n    }\n    /**\n     * @returns {number | undefined}\n     */\n    get max_steps() {\n        const ret = wasm.budget_max_steps(this.__wbg_ptr);\n        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;\n    }\n    /**\n     * @returns {number | undefined}\n     */\n    get max_time() {\n        const ret = wasm.budget_max_time(this.__wbg_ptr);\n        return ret[0] === 0 ? undefined : ret[1];\n    }\n    /**\n     * @returns {Budget}\n     */\n    static new() {\n        const ret = wasm.budget_new();\n        return Budget.__wrap(ret);\n    }\n    /**\n     * @param {number | null} [max_size]\n     */\n    set max_size(max_size) {\n        wasm.budget_set_max_size(this.__wbg_ptr, isLikeNone(max_size) ? Number.MAX_SAFE_INTEGER : (max_size) >>> 0);\n    }\n    /**\n     * @param {number | null} [max_steps]\n     */\n    set max_steps(max_steps) {\n        wasm.budget_set_max_steps(this.__wbg_ptr, isLikeNone(max_steps) ? Number.MAX_SAFE_INTEGER : (max_steps) >>> 0);\n    }\n    /**\n     * @param {number | null} [max_time]\n     */\n    set max_time(max_time) {\n        wasm.budget_set_max_time(this.__wbg_ptr, !isLikeNone(max_time), isLikeNone(max_time) ? 0 : max_time);\n    }\n    /**\n     * @returns {Budget}\n     */\n    static unlimited() {\n        const ret = wasm.budget_unlimited();\n        return Budget.__wrap(ret);\n    }\n}\nif (Symbol.dispose) Budget.prototype[Symbol.dispose] = Budget.prototype.free;\n\n/**\n * @enum {0 | 1 | 2 | 3}\n */\nconst Equivalence = Object.freeze({\n    AlphaEquivalent: 0, \"0\": \"AlphaEquivalent\",\n    BetaEquivalent: 1, \"1\": \"BetaEquivalent\",\n    Different: 2, \"2\": \"Different\",\n    Undecided: 3, \"3\": \"Undecided\",\n});\n\n/**\n * @enum {0 | 1 | 2 | 3 | 4 | 5 | 6 | 7}\n */\nconst ErrorKind = Object.freeze({\n    Syntax: 0, \"0\": \"Syntax\",\n    Naming: 1, \"1\": \"Naming\",\n    Input: 2, \"2\": \"Input\",\n    Arithmetic: 3, \"3\": \"Arithmetic\",\n    Budget: 4, \"4\": \"Budget\",\n    Assertion: 5, \"5\": \"Assertion\",\n    Divergence: 6, \"6\": \"Divergence\",\n    Internal: 7, \"7\": \"Internal\",\n});\n\nclass ErrorReport {\n    static __wrap(ptr) {\n        const obj = Object.create(ErrorReport.prototype);\n        obj.__wbg_ptr = ptr;\n        ErrorReportFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        ErrorReportFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_errorreport_free(ptr, 0);\n    }\n    /**\n     * @returns {ErrorKind}\n     */\n    get kind() {\n        const ret = wasm.__wbg_get_errorreport_kind(this.__wbg_ptr);\n        return ret;\n    }\n    /**\n     * @returns {string}\n     */\n    get message() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.__wbg_get_errorreport_message(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n    /**\n     * @returns {Span | undefined}\n     */\n    get span() {\n        const ret = wasm.__wbg_get_errorreport_span(this.__wbg_ptr);\n        return ret === 0 ? undefined : Span.__wrap(ret);\n    }\n    /**\n     * @param {ErrorKind} arg0\n     */\n    set kind(arg0) {\n        wasm.__wbg_set_errorreport_kind(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {string} arg0\n     */\n    set message(arg0) {\n        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_errorreport_message(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {Span | null} [arg0]\n     */\n    set span(arg0) {\n        let ptr0 = 0;\n        if (!isLikeNone(arg0)) {\n            _assertClass(arg0, Span);\n            ptr0 = arg0.__destroy_into_raw();\n        }\n        wasm.__wbg_set_errorreport_span(this.__wbg_ptr, ptr0);\n    }\n}\nif (Symbol.dispose) ErrorReport.prototype[Symbol.dispose] = ErrorReport.prototype.free;\n\n/**\n * @enum {0 | 1 | 2}\n */\nconst Eta = Object.freeze({\n    Off: 0, \"0\": \"Off\",\n    Reduce: 1, \"1\": \"Reduce\",\n    Expand: 2, \"2\": \"Expand\",\n});\n\nclass EvalResult {\n    static __wrap(ptr) {\n        const obj = Object.create(EvalResult.prototype);\n        obj.__wbg_ptr = ptr;\n        EvalResultFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        EvalResultFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_evalresult_free(ptr, 0);\n    }\n    /**\n     * @returns {ErrorKind | undefined}\n     */\n    get error_kind() {\n        const ret = wasm.__wbg_get_evalresult_error_kind(this.__wbg_ptr);\n        return ret === 8 ? undefined : ret;\n    }\n    /**\n     * @returns {string}\n     */\n    get output() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.__wbg_get_evalresult_output(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n    /**\n     * @returns {number}\n     */\n    get size() {\n        const ret = wasm.__wbg_get_evalresult_size(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @returns {Span | undefined}\n     */\n    get span() {\n        const ret = wasm.__wbg_get_evalresult_span(this.__wbg_ptr);\n        return ret === 0 ? undefined : Span.__wrap(ret);\n    }\n    /**\n     * @returns {Status}\n     */\n    get status() {\n        const ret = wasm.__wbg_get_evalresult_status(this.__wbg_ptr);\n        return ret;\n    }\n    /**\n     * @returns {number}\n     */\n    get steps() {\n        const ret = wasm.__wbg_get_evalresult_steps(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @param {ErrorKind | null} [arg0]\n     */\n    set error_kind(arg0) {\n        wasm.__wbg_set_evalresult_error_kind(this.__wbg_ptr, isLikeNone(arg0) ? 8 : arg0);\n    }\n    /**\n     * @param {string} arg0\n     */\n    set output(arg0) {\n        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_evalresult_output(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {number} arg0\n     */\n    set size(arg0) {\n        wasm.__wbg_set_evalresult_size(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {Span | null} [arg0]\n     */\n    set span(arg0) {\n        let ptr0 = 0;\n        if (!isLikeNone(arg0)) {\n            _assertClass(arg0, Span);\n            ptr0 = arg0.__destroy_into_raw();\n        }\n        wasm.__wbg_set_evalresult_span(this.__wbg_ptr, ptr0);\n    }\n    /**\n     * @param {Status} arg0\n     */\n    set status(arg0) {\n        wasm.__wbg_set_evalresult_status(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {number} arg0\n     */\n    set steps(arg0) {\n        wasm.__wbg_set_evalresult_steps(this.__wbg_ptr, arg0);\n    }\n}\nif (Symbol.dispose) EvalResult.prototype[Symbol.dispose] = EvalResult.prototype.free;\n\nclass LineResult {\n    static __wrap(ptr) {\n        const obj = Object.create(LineResult.prototype);\n        obj.__wbg_ptr = ptr;\n        LineResultFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        LineResultFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_lineresult_free(ptr, 0);\n    }\n    /**\n     * @returns {number}\n     */\n    get line() {\n        const ret = wasm.__wbg_get_lineresult_line(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @returns {string | undefined}\n     */\n    get name() {\n        const ret = wasm.__wbg_get_lineresult_name(this.__wbg_ptr);\n        let v1;\n        if (ret[0] !== 0) {\n            v1 = getStringFromWasm0(ret[0], ret[1]);\n            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);\n        }\n        return v1;\n    }\n    /**\n     * @returns {boolean | undefined}\n     */\n    get passed() {\n        const ret = wasm.__wbg_get_lineresult_passed(this.__wbg_ptr);\n        return ret === 0xFFFFFF ? undefined : ret !== 0;\n    }\n    /**\n     * @returns {EvalResult}\n     */\n    get result() {\n        const ret = wasm.__wbg_get_lineresult_result(this.__wbg_ptr);\n        return EvalResult.__wrap(ret);\n    }\n    /**\n     * @param {number} arg0\n     */\n    set line(arg0) {\n        wasm.__wbg_set_lineresult_line(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {string | null} [arg0]\n     */\n    set name(arg0) {\n        var ptr0 = isLikeNone(arg0) ? 0 : passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        var len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_lineresult_name(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {boolean | null} [arg0]\n     */\n    set passed(arg0) {\n        wasm.__wbg_set_lineresult_passed(this.__wbg_ptr, isLikeNone(arg0) ? 0xFFFFFF : arg0 ? 1 : 0);\n    }\n    /**\n     * @param {EvalResult} arg0\n     */\n    set result(arg0) {\n        _assertClass(arg0, EvalResult);\n        var ptr0 = arg0.__destroy_into_raw();\n        wasm.__wbg_set_lineresult_result(this.__wbg_ptr, ptr0);\n    }\n}\nif (Symbol.dispose) LineResult.prototype[Symbol.dispose] = LineResult.prototype.free;\n\nclass Parser {\n    static __wrap(ptr) {\n        const obj = Object.create(Parser.prototype);\n        obj.__wbg_ptr = ptr;\n        ParserFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        ParserFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_parser_free(ptr, 0);\n    }\n    /**\n     * @returns {Arithmetic}\n     */\n    arithmetic() {\n        const ret = wasm.parser_arithmetic(this.__wbg_ptr);\n        return ret;\n    }\n    /**\n     * @returns {Budget}\n     */\n    budget() {\n        const ret = wasm.parser_budget(this.__wbg_ptr);\n        return Budget.__wrap(ret);\n    }\n    /**\n     * @param {string} name\n     * @param {string} expression\n     * @returns {string}\n     */\n    create_keyword(name, expression) {\n        let deferred3_0;\n        let deferred3_1;\n        try {\n            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len0 = WASM_VECTOR_LEN;\n            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len1 = WASM_VECTOR_LEN;\n            const ret = wasm.parser_create_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);\n            deferred3_0 = ret[0];\n            deferred3_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);\n        }\n    }\n    /**\n     * @param {string} name\n     * @param {string} expression\n     * @returns {string}\n     */\n    define_keyword(name, expression) {\n        let deferred4_0;\n        let deferred4_1;\n        try {\n            const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len0 = WASM_VECTOR_LEN;\n            const ptr1 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n            const len1 = WASM_VECTOR_LEN;\n            const ret = wasm.parser_define_keyword(this.__wbg_ptr, ptr0, len0, ptr1, len1);\n            var ptr3 = ret[0];\n            var len3 = ret[1];\n            if (ret[3]) {\n                ptr3 = 0; len3 = 0;\n                throw takeFromExternrefTable0(ret[2]);\n            }\n            deferred4_0 = ptr3;\n            deferred4_1 = len3;\n            return getStringFromWasm0(ptr3, len3);\n        } finally {\n            wasm.__wbindgen_free(deferred4_0, deferred4_1, 1);\n        }\n    }\n    /**\n     * @param {string[]} names\n     * @param {string[]} expressions\n     */\n    define_keyword_group(names, expressions) {\n        const ptr0 = passArrayJsValueToWasm0(names, wasm.__wbindgen_malloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ptr1 = passArrayJsValueToWasm0(expressions, wasm.__wbindgen_malloc);\n        const len1 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_define_keyword_group(this.__wbg_ptr, ptr0, len0, ptr1, len1);\n        if (ret[1]) {\n            throw takeFromExternrefTable0(ret[0]);\n        }\n    }\n    /**\n     * @param {string} name\n     * @returns {string[]}\n     */\n    dependents_of(name) {\n        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_dependents_of(this.__wbg_ptr, ptr0, len0);\n        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n        return v2;\n    }\n    /**\n     * @returns {Eta}\n     */\n    eta() {\n        const ret = wasm.parser_eta(this.__wbg_ptr);\n        return ret;\n    }\n    /**\n     * @param {string} name\n     * @returns {string | undefined}\n     */\n    keyword_expression(name) {\n        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_keyword_expression(this.__wbg_ptr, ptr0, len0);\n        let v2;\n        if (ret[0] !== 0) {\n            v2 = getStringFromWasm0(ret[0], ret[1]);\n            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);\n        }\n        return v2;\n    }\n    /**\n     * @returns {string[]}\n     */\n    keyword_names() {\n        const ret = wasm.parser_keyword_names(this.__wbg_ptr);\n        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n        return v1;\n    }\n    /**\n     * @param {string} text\n     * @returns {LineResult[]}\n     */\n    load_program(text) {\n        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_load_program(this.__wbg_ptr, ptr0, len0);\n        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n        return v2;\n    }\n    /**\n     * @param {string} text\n     * @param {Strategy} strategy\n     * @returns {LineResult[]}\n     */\n    load_program_with(text, strategy) {\n        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_load_program_with(this.__wbg_ptr, ptr0, len0, strategy);\n        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n        return v2;\n    }\n    /**\n     * @returns {Parser}\n     */\n    static new() {\n        const ret = wasm.parser_new();\n        return Parser.__wrap(ret);\n    }\n    /**\n     * @param {string} name\n     */\n    remove_keyword(name) {\n        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.parser_remove_keyword(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {string} text\n     * @returns {TestReport}\n     */\n    run_tests(text) {\n        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_run_tests(this.__wbg_ptr, ptr0, len0);\n        return TestReport.__wrap(ret);\n    }\n    /**\n     * @param {string} text\n     * @param {Strategy} strategy\n     * @returns {TestReport}\n     */\n    run_tests_with(text, strategy) {\n        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        const ret = wasm.parser_run_tests_with(this.__wbg_ptr, ptr0, len0, strategy);\n        return TestReport.__wrap(ret);\n    }\n    /**\n     * @param {Arithmetic} arithmetic\n     */\n    set_arithmetic(arithmetic) {\n        wasm.parser_set_arithmetic(this.__wbg_ptr, arithmetic);\n    }\n    /**\n     * @param {Budget} budget\n     */\n    set_budget(budget) {\n        _assertClass(budget, Budget);\n        wasm.parser_set_budget(this.__wbg_ptr, budget.__wbg_ptr);\n    }\n    /**\n     * @param {Eta} eta\n     */\n    set_eta(eta) {\n        wasm.parser_set_eta(this.__wbg_ptr, eta);\n    }\n    /**\n     * @param {Style} style\n     */\n    set_style(style) {\n        _assertClass(style, Style);\n        wasm.parser_set_style(this.__wbg_ptr, style.__wbg_ptr);\n    }\n    /**\n     * @returns {Style}\n     */\n    style() {\n        const ret = wasm.parser_style(this.__wbg_ptr);\n        return Style.__wrap(ret);\n    }\n    /**\n     * @returns {Parser}\n     */\n    static with_prelude() {\n        const ret = wasm.parser_with_prelude();\n        return Parser.__wrap(ret);\n    }\n}\nif (Symbol.dispose) Parser.prototype[Symbol.dispose] = Parser.prototype.free;\n\nclass Span {\n    static __wrap(ptr) {\n        const obj = Object.create(Span.prototype);\n        obj.__wbg_ptr = ptr;\n        SpanFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        SpanFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_span_free(ptr, 0);\n    }\n    /**\n     * @returns {number}\n     */\n    get end() {\n        const ret = wasm.span_end(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @returns {number}\n     */\n    get start() {\n        const ret = wasm.span_start(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n}\nif (Symbol.dispose) Span.prototype[Symbol.dispose] = Span.prototype.free;\n\n/**\n * @enum {0 | 1 | 2 | 3}\n */\nconst Status = Object.freeze({\n    NormalForm: 0, \"0\": \"NormalForm\",\n    Error: 1, \"1\": \"Error\",\n    BudgetExhausted: 2, \"2\": \"BudgetExhausted\",\n    Diverged: 3, \"3\": \"Diverged\",\n});\n\n/**\n * @enum {0 | 1 | 2 | 3}\n */\nconst Strategy = Object.freeze({\n    Normal: 0, \"0\": \"Normal\",\n    Applicative: 1, \"1\": \"Applicative\",\n    CallByName: 2, \"2\": \"CallByName\",\n    CallByValue: 3, \"3\": \"CallByValue\",\n});\n\nclass Style {\n    static __wrap(ptr) {\n        const obj = Object.create(Style.prototype);\n        obj.__wbg_ptr = ptr;\n        StyleFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        StyleFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_style_free(ptr, 0);\n    }\n    /**\n     * @returns {boolean}\n     */\n    get ascii() {\n        const ret = wasm.style_ascii(this.__wbg_ptr);\n        return ret !== 0;\n    }\n    /**\n     * @returns {boolean}\n     */\n    get compress_binders() {\n        const ret = wasm.style_compress_binders(this.__wbg_ptr);\n        return ret !== 0;\n    }\n    /**\n     * @returns {boolean}\n     */\n    get decode() {\n        const ret = wasm.style_decode(this.__wbg_ptr);\n        return ret !== 0;\n    }\n    /**\n     * @returns {boolean}\n     */\n    get fold_keywords() {\n        const ret = wasm.style_fold_keywords(this.__wbg_ptr);\n        return ret !== 0;\n    }\n    /**\n     * @returns {boolean}\n     */\n    get minimal_parens() {\n        const ret = wasm.style_minimal_parens(this.__wbg_ptr);\n        return ret !== 0;\n    }\n    /**\n     * @returns {Style}\n     */\n    static new() {\n        const ret = wasm.style_new();\n        return Style.__wrap(ret);\n    }\n    /**\n     * @param {boolean} ascii\n     */\n    set ascii(ascii) {\n        wasm.style_set_ascii(this.__wbg_ptr, ascii);\n    }\n    /**\n     * @param {boolean} compress_binders\n     */\n    set compress_binders(compress_binders) {\n        wasm.style_set_compress_binders(this.__wbg_ptr, compress_binders);\n    }\n    /**\n     * @param {boolean} decode\n     */\n    set decode(decode) {\n        wasm.style_set_decode(this.__wbg_ptr, decode);\n    }\n    /**\n     * @param {boolean} fold_keywords\n     */\n    set fold_keywords(fold_keywords) {\n        wasm.style_set_fold_keywords(this.__wbg_ptr, fold_keywords);\n    }\n    /**\n     * @param {boolean} minimal_parens\n     */\n    set minimal_parens(minimal_parens) {\n        wasm.style_set_minimal_parens(this.__wbg_ptr, minimal_parens);\n    }\n    /**\n     * @param {number | null} [width]\n     */\n    set width(width) {\n        wasm.style_set_width(this.__wbg_ptr, isLikeNone(width) ? Number.MAX_SAFE_INTEGER : (width) >>> 0);\n    }\n    /**\n     * @returns {number | undefined}\n     */\n    get width() {\n        const ret = wasm.style_width(this.__wbg_ptr);\n        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;\n    }\n}\nif (Symbol.dispose) Style.prototype[Symbol.dispose] = Style.prototype.free;\n\nclass TestFailure {\n    static __wrap(ptr) {\n        const obj = Object.create(TestFailure.prototype);\n        obj.__wbg_ptr = ptr;\n        TestFailureFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    static __unwrap(jsValue) {\n        if (!(jsValue instanceof TestFailure)) {\n            return 0;\n        }\n        return jsValue.__destroy_into_raw();\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        TestFailureFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_testfailure_free(ptr, 0);\n    }\n    /**\n     * @returns {number}\n     */\n    get line() {\n        const ret = wasm.__wbg_get_testfailure_line(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @returns {string}\n     */\n    get message() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.__wbg_get_testfailure_message(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n    /**\n     * @returns {Span | undefined}\n     */\n    get span() {\n        const ret = wasm.__wbg_get_testfailure_span(this.__wbg_ptr);\n        return ret === 0 ? undefined : Span.__wrap(ret);\n    }\n    /**\n     * @param {number} arg0\n     */\n    set line(arg0) {\n        wasm.__wbg_set_testfailure_line(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {string} arg0\n     */\n    set message(arg0) {\n        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_testfailure_message(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {Span | null} [arg0]\n     */\n    set span(arg0) {\n        let ptr0 = 0;\n        if (!isLikeNone(arg0)) {\n            _assertClass(arg0, Span);\n            ptr0 = arg0.__destroy_into_raw();\n        }\n        wasm.__wbg_set_testfailure_span(this.__wbg_ptr, ptr0);\n    }\n}\nif (Symbol.dispose) TestFailure.prototype[Symbol.dispose] = TestFailure.prototype.free;\n\nclass TestReport {\n    static __wrap(ptr) {\n        const obj = Object.create(TestReport.prototype);\n        obj.__wbg_ptr = ptr;\n        TestReportFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        TestReportFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_testreport_free(ptr, 0);\n    }\n    /**\n     * @returns {number}\n     */\n    get failed() {\n        const ret = wasm.__wbg_get_testreport_failed(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @returns {TestFailure[]}\n     */\n    get failures() {\n        const ret = wasm.__wbg_get_testreport_failures(this.__wbg_ptr);\n        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n        return v1;\n    }\n    /**\n     * @returns {number}\n     */\n    get passed() {\n        const ret = wasm.__wbg_get_testreport_passed(this.__wbg_ptr);\n        return ret >>> 0;\n    }\n    /**\n     * @param {number} arg0\n     */\n    set failed(arg0) {\n        wasm.__wbg_set_testreport_failed(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @param {TestFailure[]} arg0\n     */\n    set failures(arg0) {\n        const ptr0 = passArrayJsValueToWasm0(arg0, wasm.__wbindgen_malloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_testreport_failures(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {number} arg0\n     */\n    set passed(arg0) {\n        wasm.__wbg_set_testreport_passed(this.__wbg_ptr, arg0);\n    }\n    /**\n     * @returns {string}\n     */\n    summary() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.testreport_summary(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n}\nif (Symbol.dispose) TestReport.prototype[Symbol.dispose] = TestReport.prototype.free;\n\nclass TraceStep {\n    static __wrap(ptr) {\n        const obj = Object.create(TraceStep.prototype);\n        obj.__wbg_ptr = ptr;\n        TraceStepFinalization.register(obj, obj.__wbg_ptr, obj);\n        return obj;\n    }\n    __destroy_into_raw() {\n        const ptr = this.__wbg_ptr;\n        this.__wbg_ptr = 0;\n        TraceStepFinalization.unregister(this);\n        return ptr;\n    }\n    free() {\n        const ptr = this.__destroy_into_raw();\n        wasm.__wbg_tracestep_free(ptr, 0);\n    }\n    /**\n     * @returns {Status | undefined}\n     */\n    get failure() {\n        const ret = wasm.__wbg_get_tracestep_failure(this.__wbg_ptr);\n        return ret === 4 ? undefined : ret;\n    }\n    /**\n     * @returns {string}\n     */\n    get redex() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.__wbg_get_tracestep_redex(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n    /**\n     * @returns {string}\n     */\n    get term() {\n        let deferred1_0;\n        let deferred1_1;\n        try {\n            const ret = wasm.__wbg_get_tracestep_term(this.__wbg_ptr);\n            deferred1_0 = ret[0];\n            deferred1_1 = ret[1];\n            return getStringFromWasm0(ret[0], ret[1]);\n        } finally {\n            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);\n        }\n    }\n    /**\n     * @param {Status | null} [arg0]\n     */\n    set failure(arg0) {\n        wasm.__wbg_set_tracestep_failure(this.__wbg_ptr, isLikeNone(arg0) ? 4 : arg0);\n    }\n    /**\n     * @param {string} arg0\n     */\n    set redex(arg0) {\n        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_tracestep_redex(this.__wbg_ptr, ptr0, len0);\n    }\n    /**\n     * @param {string} arg0\n     */\n    set term(arg0) {\n        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        wasm.__wbg_set_tracestep_term(this.__wbg_ptr, ptr0, len0);\n    }\n}\nif (Symbol.dispose) TraceStep.prototype[Symbol.dispose] = TraceStep.prototype.free;\n\n/**\n * @param {string} a\n * @param {string} b\n * @param {Parser} parser\n * @returns {Equivalence}\n */\nfunction equivalent(a, b, parser) {\n    const ptr0 = passStringToWasm0(a, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len0 = WASM_VECTOR_LEN;\n    const ptr1 = passStringToWasm0(b, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len1 = WASM_VECTOR_LEN;\n    _assertClass(parser, Parser);\n    const ret = wasm.equivalent(ptr0, len0, ptr1, len1, parser.__wbg_ptr);\n    if (ret[2]) {\n        throw takeFromExternrefTable0(ret[1]);\n    }\n    return ret[0];\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @param {Strategy} strategy\n * @returns {EvalResult}\n */\nfunction evaluate(expression, parser, strategy) {\n    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len0 = WASM_VECTOR_LEN;\n    _assertClass(parser, Parser);\n    const ret = wasm.evaluate(ptr0, len0, parser.__wbg_ptr, strategy);\n    return EvalResult.__wrap(ret);\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @param {Strategy} strategy\n * @param {Budget} budget\n * @returns {EvalResult}\n */\nfunction evaluate_within(expression, parser, strategy, budget) {\n    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len0 = WASM_VECTOR_LEN;\n    _assertClass(parser, Parser);\n    _assertClass(budget, Budget);\n    const ret = wasm.evaluate_within(ptr0, len0, parser.__wbg_ptr, strategy, budget.__wbg_ptr);\n    return EvalResult.__wrap(ret);\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @returns {string}\n */\nfunction simplify(expression, parser) {\n    let deferred2_0;\n    let deferred2_1;\n    try {\n        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        _assertClass(parser, Parser);\n        const ret = wasm.simplify(ptr0, len0, parser.__wbg_ptr);\n        deferred2_0 = ret[0];\n        deferred2_1 = ret[1];\n        return getStringFromWasm0(ret[0], ret[1]);\n    } finally {\n        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);\n    }\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @param {Strategy} strategy\n * @returns {string}\n */\nfunction simplify_with(expression, parser, strategy) {\n    let deferred2_0;\n    let deferred2_1;\n    try {\n        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        _assertClass(parser, Parser);\n        const ret = wasm.simplify_with(ptr0, len0, parser.__wbg_ptr, strategy);\n        deferred2_0 = ret[0];\n        deferred2_1 = ret[1];\n        return getStringFromWasm0(ret[0], ret[1]);\n    } finally {\n        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);\n    }\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @param {Strategy} strategy\n * @param {Budget} budget\n * @returns {string}\n */\nfunction simplify_within(expression, parser, strategy, budget) {\n    let deferred2_0;\n    let deferred2_1;\n    try {\n        const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n        const len0 = WASM_VECTOR_LEN;\n        _assertClass(parser, Parser);\n        _assertClass(budget, Budget);\n        const ret = wasm.simplify_within(ptr0, len0, parser.__wbg_ptr, strategy, budget.__wbg_ptr);\n        deferred2_0 = ret[0];\n        deferred2_1 = ret[1];\n        return getStringFromWasm0(ret[0], ret[1]);\n    } finally {\n        wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);\n    }\n}\n\n/**\n * @param {string} expression\n * @param {Parser} parser\n * @param {Strategy} strategy\n * @returns {TraceStep[]}\n */\nfunction trace(expression, parser, strategy) {\n    const ptr0 = passStringToWasm0(expression, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len0 = WASM_VECTOR_LEN;\n    _assertClass(parser, Parser);\n    const ret = wasm.trace(ptr0, len0, parser.__wbg_ptr, strategy);\n    var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]);\n    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);\n    return v2;\n}\nfunction __wbg___wbindgen_string_get_0380ccaa2f57f0d9(arg0, arg1) {\n    const obj = arg1;\n    const ret = typeof(obj) === 'string' ? obj : undefined;\n    var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    var len1 = WASM_VECTOR_LEN;\n    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);\n    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);\n}\nfunction __wbg___wbindgen_throw_41e9ee4f547fc59a(arg0, arg1) {\n    throw new Error(getStringFromWasm0(arg0, arg1));\n}\nfunction __wbg_error_757e9472f8410341(arg0, arg1) {\n    let deferred0_0;\n    let deferred0_1;\n    try {\n        deferred0_0 = arg0;\n        deferred0_1 = arg1;\n        console.error(getStringFromWasm0(arg0, arg1));\n    } finally {\n        wasm.__wbindgen_free(deferred0_0, deferred0_1, 1);\n    }\n}\nfunction __wbg_errorreport_new(arg0) {\n    const ret = ErrorReport.__wrap(arg0);\n    return ret;\n}\nfunction __wbg_lineresult_new(arg0) {\n    const ret = LineResult.__wrap(arg0);\n    return ret;\n}\nfunction __wbg_new_227d7c05414eb861() {\n    const ret = new Error();\n    return ret;\n}\nfunction __wbg_now_aa4ccb83129e9e55() {\n    const ret = Date.now();\n    return ret;\n}\nfunction __wbg_stack_3b0d974bbf31e44f(arg0, arg1) {\n    const ret = arg1.stack;\n    const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);\n    const len1 = WASM_VECTOR_LEN;\n    getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);\n    getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);\n}\nfunction __wbg_testfailure_new(arg0) {\n    const ret = TestFailure.__wrap(arg0);\n    return ret;\n}\nfunction __wbg_testfailure_unwrap(arg0) {\n    const ret = TestFailure.__unwrap(arg0);\n    return ret;\n}\nfunction __wbg_tracestep_new(arg0) {\n    const ret = TraceStep.__wrap(arg0);\n    return ret;\n}\nfunction __wbindgen_generic_0000000000000001(arg0, arg1) {\n    // Cast intrinsic for `Ref(String) -> Externref`.\n    const ret = getStringFromWasm0(arg0, arg1);\n    return ret;\n}\nfunction __wbindgen_init_externref_table() {\n    const table = wasm.__wbindgen_externrefs;\n    const offset = table.grow(4);\n    table.set(0, undefined);\n    table.set(offset + 0, undefined);\n    table.set(offset + 1, null);\n    table.set(offset + 2, true);\n    table.set(offset + 3, false);\n}\nconst BudgetFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_budget_free(ptr, 1));\nconst ErrorReportFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_errorreport_free(ptr, 1));\nconst EvalResultFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_evalresult_free(ptr, 1));\nconst LineResultFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_lineresult_free(ptr, 1));\nconst ParserFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_parser_free(ptr, 1));\nconst SpanFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_span_free(ptr, 1));\nconst StyleFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_style_free(ptr, 1));\nconst TestFailureFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_testfailure_free(ptr, 1));\nconst TestReportFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_testreport_free(ptr, 1));\nconst TraceStepFinalization = (typeof FinalizationRegistry === 'undefined')\n    ? { register: () => {}, unregister: () => {} }\n    : new FinalizationRegistry(ptr => wasm.__wbg_tracestep_free(ptr, 1));\n\nfunction addToExternrefTable0(obj) {\n    const idx = wasm.__externref_table_alloc();\n    wasm.__wbindgen_externrefs.set(idx, obj);\n    return idx;\n}\n\nfunction _assertClass(instance, klass) {\n    if (!(instance instanceof klass)) {\n        throw new Error(`expected instance of ${klass.name}`);\n    }\n}\n\nfunction getArrayJsValueFromWasm0(ptr, len) {\n    ptr = ptr >>> 0;\n    const mem = getDataViewMemory0();\n    const result = [];\n    for (let i = ptr; i < ptr + 4 * len; i += 4) {\n        result.push(wasm.__wbindgen_externrefs.get(mem.getUint32(i, true)));\n    }\n    wasm.__externref_drop_slice(ptr, len);\n    return result;\n}\n\nlet cachedDataViewMemory0 = null;\nfunction getDataViewMemory0() {\n    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {\n        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);\n    }\n    return cachedDataViewMemory0;\n}\n\nfunction getStringFromWasm0(ptr, len) {\n    return decodeText(ptr >>> 0, len);\n}\n\nlet cachedUint8ArrayMemory0 = null;\nfunction getUint8ArrayMemory0() {\n    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {\n        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);\n    }\n    return cachedUint8ArrayMemory0;\n}\n\nfunction isLikeNone(x) {\n    return x === undefined || x === null;\n}\n\nfunction passArrayJsValueToWasm0(array, malloc) {\n    const ptr = malloc(array.length * 4, 4) >>> 0;\n    for (let i = 0; i < array.length; i++) {\n        const add = addToExternrefTable0(array[i]);\n        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);\n    }\n    WASM_VECTOR_LEN = array.length;\n    return ptr;\n}\n\nfunction passStringToWasm0(arg, malloc, realloc) {\n    if (realloc === undefined) {\n        const buf = cachedTextEncoder.encode(arg);\n        const ptr = malloc(buf.length, 1) >>> 0;\n        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);\n        WASM_VECTOR_LEN = buf.length;\n        return ptr;\n    }\n\n    let len = arg.length;\n    let ptr = malloc(len, 1) >>> 0;\n\n    const mem = getUint8ArrayMemory0();\n\n    let offset = 0;\n\n    for (; offset < len; offset++) {\n        const code = arg.charCodeAt(offset);\n        if (code > 0x7F) break;\n        mem[ptr + offset] = code;\n    }\n    if (offset !== len) {\n        if (offset !== 0) {\n            arg = arg.slice(offset);\n        }\n        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;\n        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);\n        const ret = cachedTextEncoder.encodeInto(arg, view);\n\n        offset += ret.written;\n        ptr = realloc(ptr, len, offset, 1) >>> 0;\n    }\n\n    WASM_VECTOR_LEN = offset;\n    return ptr;\n}\n\nfunction takeFromExternrefTable0(idx) {\n    const value = wasm.__wbindgen_externrefs.get(idx);\n    wasm.__externref_table_dealloc(idx);\n    return value;\n}\n\nlet cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });\ncachedTextDecoder.decode();\nconst MAX_SAFARI_DECODE_BYTES = 2146435072;\nlet numBytesDecoded = 0;\nfunction decodeText(ptr, len) {\n    numBytesDecoded += len;\n    if (numBytesDecoded >= MAX_SAFARI_DECODE_BYTES) {\n        cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });\n        cachedTextDecoder.decode();\n        numBytesDecoded = len;\n    }\n    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));\n}\n\nconst cachedTextEncoder = new TextEncoder();\n\nif (!('encodeInto' in cachedTextEncoder)) {\n    cachedTextEncoder.encodeInto = function (arg, view) {\n        const buf = cachedTextEncoder.encode(arg);\n        view.set(buf);\n        return {\n            read: arg.length,\n            written: buf.length\n        };\n    };\n}\n\nlet WASM_VECTOR_LEN = 0;\n\n\nlet wasm;\nfunction __wbg_set_wasm(val) {\n    wasm = val;\n}\n\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator_bg.js?\n}");
 
 /***/ }),
 
@@ -36,7 +36,7 @@ eval("{__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpa
   \****************************************/
 /***/ ((module, exports, __webpack_require__) => {
 
-eval("{/* harmony import */ var WEBPACK_IMPORTED_MODULE_0 = __webpack_require__(/*! ./lambda_calculator_bg.js */ \"../pkg/lambda_calculator_bg.js\");\nmodule.exports = __webpack_require__.v(exports, module.id, \"51bf962f7d5e995fb5ef\", {\n\t\"./lambda_calculator_bg.js\": {\n\t\t\"__wbg_new_8a6f238a6ece86ea\": WEBPACK_IMPORTED_MODULE_0.__wbg_new_8a6f238a6ece86ea,\n\t\t\"__wbg_stack_0ed75d68575b0f3c\": WEBPACK_IMPORTED_MODULE_0.__wbg_stack_0ed75d68575b0f3c,\n\t\t\"__wbg_error_7534b8e9a36f1ab4\": WEBPACK_IMPORTED_MODULE_0.__wbg_error_7534b8e9a36f1ab4,\n\t\t\"__wbindgen_throw\": WEBPACK_IMPORTED_MODULE_0.__wbindgen_throw,\n\t\t\"__wbindgen_init_externref_table\": WEBPACK_IMPORTED_MODULE_0.__wbindgen_init_externref_table\n\t}\n});\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator_bg.wasm?\n}");
+eval("{/* harmony import */ var WEBPACK_IMPORTED_MODULE_0 = __webpack_require__(/*! ./lambda_calculator_bg.js */ \"../pkg/lambda_calculator_bg.js\");\nmodule.exports = __webpack_require__.v(exports, module.id, \"ea8fc1c19ec63e0d9ce3\", {\n\t\"./lambda_calculator_bg.js\": {\n\t\t\"__wbg_errorreport_new\": WEBPACK_IMPORTED_MODULE_0.__wbg_errorreport_new,\n\t\t\"__wbg_testfailure_unwrap\": WEBPACK_IMPORTED_MODULE_0.__wbg_testfailure_unwrap,\n\t\t\"__wbg_lineresult_new\": WEBPACK_IMPORTED_MODULE_0.__wbg_lineresult_new,\n\t\t\"__wbg_testfailure_new\": WEBPACK_IMPORTED_MODULE_0.__wbg_testfailure_new,\n\t\t\"__wbg_tracestep_new\": WEBPACK_IMPORTED_MODULE_0.__wbg_tracestep_new,\n\t\t\"__wbg_now_aa4ccb83129e9e55\": WEBPACK_IMPORTED_MODULE_0.__wbg_now_aa4ccb83129e9e55,\n\t\t\"__wbg_new_227d7c05414eb861\": WEBPACK_IMPORTED_MODULE_0.__wbg_new_227d7c05414eb861,\n\t\t\"__wbg_stack_3b0d974bbf31e44f\": WEBPACK_IMPORTED_MODULE_0.__wbg_stack_3b0d974bbf31e44f,\n\t\t\"__wbg_error_757e9472f8410341\": WEBPACK_IMPORTED_MODULE_0.__wbg_error_757e9472f8410341,\n\t\t\"__wbg___wbindgen_throw_41e9ee4f547fc59a\": WEBPACK_IMPORTED_MODULE_0.__wbg___wbindgen_throw_41e9ee4f547fc59a,\n\t\t\"__wbg___wbindgen_string_get_0380ccaa2f57f0d9\": WEBPACK_IMPORTED_MODULE_0.__wbg___wbindgen_string_get_0380ccaa2f57f0d9,\n\t\t\"__wbindgen_init_externref_table\": WEBPACK_IMPORTED_MODULE_0.__wbindgen_init_externref_table,\n\t\t\"__wbindgen_generic_0000000000000001\": WEBPACK_IMPORTED_MODULE_0.__wbindgen_generic_0000000000000001\n\t}\n});\n\n//# sourceURL=webpack://create-wasm-app/../pkg/lambda_calculator_bg.wasm?\n}");
 
 /***/ }),
 
@@ -46,7 +46,7 @@ eval("{/* harmony import */ var WEBPACK_IMPORTED_MODULE_0 = __webpack_require__(
   \***************************/
 /***/ ((module, __webpack_exports__, __webpack_require__) => {
 
-eval("{__webpack_require__.a(module, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   add_table_row: () => (/* binding */ add_table_row)\n/* harmony export */ });\n/* harmony import */ var lambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lambda-calc-tool */ \"../pkg/lambda_calculator.js\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([lambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__]);\nlambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__ = (__webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__)[0];\n\n\n// -- Imports -- /\n\n\n\n// -- Exports -- //\n\n/**\n * @param {string} name\n * @param {string} expression\n * @param {Parser} parser\n */\nconst add_table_row = (name, expression, parser) => {\n\tconst named_row = get_named_row(name, parser);\n\tnamed_row.cells[1].textContent = expression;\n}\n\n// -- Impls -- //\n\n/** @type {HTMLTableSectionElement} */\nconst tbody = document.getElementById(\"table-body\");\n\n/**\n * @param {string} name\n * @param {Parser} parser\n * @return {HTMLTableRowElement}x\n */\nconst get_named_row = (name, parser) => {\n\tfor (let i = 0; i < tbody.rows.length; ++i) {\n\t\tif (tbody.rows[i].cells[0].textContent === name) {\n\t\t\treturn tbody.rows[i];\n\t\t}\n\t}\n\n\tconst named_row = tbody.insertRow();\n\tconst del_button = document.createElement(\"button\");\n\tdel_button.type = \"button\";\n\tdel_button.className = \"button-delete-shorthand\";\n\tdel_button.textContent = \"–\";\n\tdel_button.title = \"Remove Shorthand\";\n\tdel_button.addEventListener(\"click\", (_) => {\n\t\tparser.remove_keyword(named_row.cells[0].textContent);\n\t\tnamed_row.remove();\n\t});\n\n\tnamed_row.insertCell(0).textContent = name;\n\tnamed_row.insertCell(1);\n\tnamed_row.insertCell(2).appendChild(del_button);\n\n\treturn named_row;\n}\n\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });\n\n//# sourceURL=webpack://create-wasm-app/./modules/tabled.js?\n}");
+eval("{__webpack_require__.a(module, async (__webpack_handle_async_dependencies__, __webpack_async_result__) => { try {\n__webpack_require__.r(__webpack_exports__);\n/* harmony export */ __webpack_require__.d(__webpack_exports__, {\n/* harmony export */   add_table_row: () => (/* binding */ add_table_row)\n/* harmony export */ });\n/* harmony import */ var lambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__ = __webpack_require__(/*! lambda-calc-tool */ \"../pkg/lambda_calculator.js\");\nvar __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([lambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__]);\nlambda_calc_tool__WEBPACK_IMPORTED_MODULE_0__ = (__webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__)[0];\n\n// -- Imports -- /\n\n\n\n// -- Exports -- //\n\n/**\n * @param {string} name\n * @param {string} expression\n * @param {Parser} parser\n */\nconst add_table_row = (name, expression, parser) => {\n\tconst named_row = get_named_row(name, parser);\n\tnamed_row.cells[1].textContent = expression;\n}\n\n// -- Impls -- //\n\n/** @type {HTMLTableSectionElement} */\nconst tbody = document.getElementById(\"table-body\");\n\n/**\n * @param {string} name\n * @param {Parser} parser\n * @return {HTMLTableRowElement}x\n */\nconst get_named_row = (name, parser) => {\n\tfor (let i = 0; i < tbody.rows.length; ++i) {\n\t\tif (tbody.rows[i].cells[0].textContent === name) {\n\t\t\treturn tbody.rows[i];\n\t\t}\n\t}\n\n\tconst named_row = tbody.insertRow();\n\tconst del_button = document.createElement(\"button\");\n\tdel_button.type = \"button\";\n\tdel_button.className = \"button-delete-shorthand\";\n\tdel_button.textContent = \"–\";\n\tdel_button.title = \"Remove Shorthand\";\n\tdel_button.addEventListener(\"click\", (_) => {\n\t\tparser.remove_keyword(named_row.cells[0].textContent);\n\t\tnamed_row.remove();\n\t});\n\n\tnamed_row.insertCell(0).textContent = name;\n\tnamed_row.insertCell(1);\n\tnamed_row.insertCell(2).appendChild(del_button);\n\n\treturn named_row;\n}\n\n__webpack_async_result__();\n} catch(e) { __webpack_async_result__(e); } });\n\n//# sourceURL=webpack://create-wasm-app/./modules/tabled.js?\n}");
 
 /***/ })
 